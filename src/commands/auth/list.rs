@@ -1,31 +1,116 @@
+use std::collections::HashMap;
+
+use anyhow::{ensure, Result};
 use clap::Parser;
+use futures_util::{stream, StreamExt};
 
+use super::login::util::{token_options, TokenType};
+use super::types::TokenStatus;
 use super::utils::format_users;
+use crate::config::EXEC_NAME;
+use crate::error::HopError;
+use crate::state::http::HttpClient;
 use crate::state::State;
 
+const CHECK_CONCURRENCY: usize = 5;
+
 #[derive(Debug, Parser)]
 #[clap(about = "List all authenticated users")]
 pub struct Options {
     #[clap(short, long, help = "Only print the IDs of the authorized users")]
     pub quiet: bool,
+
+    #[clap(short, long, help = "Check if each token is still valid")]
+    pub check: bool,
 }
 
-pub fn handle(options: &Options, state: &State) {
-    let users = state.auth.authorized.keys().collect::<Vec<_>>();
+pub async fn handle(options: Options, mut state: State) -> Result<()> {
+    let mut users = state.auth.authorized.keys().cloned().collect::<Vec<_>>();
+    users.sort();
 
-    assert!(!users.is_empty(), "There are no authorized users");
+    ensure!(
+        !users.is_empty(),
+        "There are no authorized users, run `{EXEC_NAME} auth login` to login"
+    );
 
     if options.quiet {
-        let ids = users
-            .iter()
-            .map(|d| d.as_str())
-            .collect::<Vec<_>>()
-            .join(" ");
+        println!("{}", users.join(" "));
 
-        println!("{ids}");
+        return Ok(());
+    }
+
+    let statuses = if options.check {
+        Some(check_tokens(&mut state).await)
     } else {
-        let users_fmt = format_users(&users, true);
+        None
+    };
+
+    let users = users.iter().collect::<Vec<_>>();
+    let users_fmt = format_users(&users, &state.auth, statuses.as_ref(), true);
 
-        println!("{}", users_fmt.join("\n"));
+    println!("{}", users_fmt.join("\n"));
+
+    if statuses
+        .iter()
+        .flat_map(HashMap::values)
+        .any(|status| *status == TokenStatus::Revoked)
+    {
+        log::warn!("Some tokens were revoked, run `{EXEC_NAME} auth login` to login again");
     }
+
+    Ok(())
+}
+
+/// Verifies every token concurrently, refreshing the details of valid ones.
+async fn check_tokens(state: &mut State) -> HashMap<String, TokenStatus> {
+    let http = &state.http;
+
+    let results = stream::iter(state.auth.authorized.clone())
+        .map(|(id, token)| async move {
+            let client = HttpClient::with_options(
+                Some(token.clone()),
+                Some(http.base_url.clone()),
+                http.options.clone(),
+            );
+
+            let token_type = TokenType::from_token(&token).ok();
+            let result = token_options(client, token_type.clone()).await;
+
+            (id, token_type, result)
+        })
+        .buffer_unordered(CHECK_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut statuses = HashMap::new();
+    let mut changed = false;
+
+    for (id, token_type, result) in results {
+        let status = match result {
+            Ok(client) => {
+                changed |= state.auth.update_account(&client, token_type);
+
+                TokenStatus::Valid
+            }
+
+            Err(error) => match error.chain().find_map(|e| e.downcast_ref::<HopError>()) {
+                Some(HopError::Unauthorized(_) | HopError::Forbidden(_)) => TokenStatus::Revoked,
+                _ => {
+                    log::debug!("Failed to check token of `{id}`: {error}");
+
+                    TokenStatus::Unknown
+                }
+            },
+        };
+
+        statuses.insert(id, status);
+    }
+
+    if changed {
+        if let Err(error) = state.auth.save_accounts().await {
+            log::debug!("Failed to save account details: {error}");
+        }
+    }
+
+    statuses
 }
@@ -8,8 +8,9 @@ use super::WEB_AUTH_URL;
 use crate::commands::auth::login::PAT_FALLBACK_URL;
 use crate::utils::browser::listen_for_callback;
 use crate::utils::parse_key_val;
+use crate::utils::prompt::{self, Arg};
 
-pub async fn browser_login() -> Result<String> {
+pub async fn browser_login(open_browser: bool) -> Result<String> {
     let port = portpicker::pick_unused_port().with_context(|| {
         "Could not find an unused port. Please make sure you have at least one port available."
     })?;
@@ -19,16 +20,28 @@ pub async fn browser_login() -> Result<String> {
         vec!["callback", &format!("http://localhost:{port}/")].join("=")
     );
 
+    if !open_browser {
+        log::info!("Open this URL in a browser to login: {url}");
+        log::info!(
+            "If the browser is on another machine, forward the port first, e.g. `ssh -L {port}:localhost:{port} <host>`"
+        );
+        log::info!("Waiting for token to be created...");
+
+        return listen_for_callback(port, 2, |req, sender| {
+            Box::pin(request_handler(req, sender))
+        })
+        .await;
+    }
+
     // lunch a web server to handle the auth request
     if let Err(why) = webbrowser::open(&url) {
         log::error!("Could not open web a browser.");
         log::debug!("Error: {why}");
-        log::info!("Please provide a personal access token manually.");
+        log::info!("Please provide a personal access token manually, or use `--device`.");
         log::info!("You can create one at {PAT_FALLBACK_URL}");
 
         // fallback to simple input
-        dialoguer::Password::new()
-            .with_prompt("Enter your token")
+        prompt::password("Enter your token", Arg::new("token", "--token"))?
             .interact()
             .map_err(|why| anyhow!(why))
     } else {
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use console::style;
+use serde::Deserialize;
+use tokio::time::Instant;
+
+use crate::config::EXEC_NAME;
+use crate::error::HopError;
+use crate::state::http::HttpClient;
+
+const DEFAULT_POLL_INTERVAL: u64 = 5;
+
+#[derive(Debug, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_url: String,
+    /// seconds until the code expires
+    pub expires_in: u64,
+    /// seconds to wait between polls
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+fn default_interval() -> u64 {
+    DEFAULT_POLL_INTERVAL
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceStatus {
+    Pending,
+    Approved,
+    Denied,
+    Expired,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeviceAuthorization {
+    pub status: DeviceStatus,
+    pub token: Option<String>,
+}
+
+/// Logs in without a local callback, the code is approved from any
+/// device while the CLI polls for the result.
+pub async fn device_login(http: &HttpClient, open_browser: bool) -> Result<String> {
+    let code = http
+        .request::<DeviceCode>(
+            "POST",
+            "/auth/device",
+            Some((b"{}".to_vec().into(), "application/json")),
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    let url = format!("{}?code={}", code.verification_url, code.user_code);
+
+    log::info!(
+        "Open {} and confirm the code {}",
+        style(&code.verification_url).underlined(),
+        style(&code.user_code).bold()
+    );
+
+    if open_browser {
+        if let Err(why) = webbrowser::open(&url) {
+            log::debug!("Could not open a browser: {why}");
+        }
+    }
+
+    log::info!("Waiting for the login to be approved...");
+
+    poll_for_token(
+        http,
+        &code.device_code,
+        Duration::from_secs(code.interval),
+        Duration::from_secs(code.expires_in),
+    )
+    .await
+}
+
+/// Polls until the code is approved, denied or expires.
+pub async fn poll_for_token(
+    http: &HttpClient,
+    device_code: &str,
+    interval: Duration,
+    expires_in: Duration,
+) -> Result<String> {
+    let deadline = Instant::now() + expires_in;
+
+    loop {
+        let authorization = http
+            .request::<DeviceAuthorization>("GET", &format!("/auth/device/{device_code}"), None)
+            .await?
+            .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+        match authorization.status {
+            DeviceStatus::Approved => {
+                return authorization
+                    .token
+                    .ok_or_else(|| anyhow!("The login was approved but no token was sent"))
+            }
+
+            DeviceStatus::Denied => {
+                return Err(HopError::Unauthorized("The login was denied".to_string()).into())
+            }
+
+            DeviceStatus::Expired => break,
+
+            DeviceStatus::Pending => {}
+        }
+
+        if Instant::now() + interval >= deadline {
+            break;
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+
+    Err(HopError::Unauthorized(format!(
+        "The code expired before the login was approved, run `{EXEC_NAME} auth login --device` again"
+    ))
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Response, Server};
+
+    use super::*;
+
+    /// Starts a stub of the polling endpoint that answers the nth poll
+    /// with `respond(n)`.
+    fn stub_server(respond: fn(usize) -> &'static str) -> HttpClient {
+        let hits = Arc::new(AtomicUsize::new(0));
+
+        let service = make_service_fn(move |_| {
+            let hits = hits.clone();
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_| {
+                    let body = respond(hits.fetch_add(1, Ordering::SeqCst));
+
+                    async move {
+                        Ok::<_, Infallible>(Response::new(Body::from(format!(
+                            r#"{{"success":true,"data":{body}}}"#
+                        ))))
+                    }
+                }))
+            }
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+        let url = format!("http://{}", server.local_addr());
+
+        tokio::spawn(server);
+
+        HttpClient::new(None, Some(url))
+    }
+
+    async fn poll(http: &HttpClient, expires_in: Duration) -> Result<String> {
+        poll_for_token(http, "device", Duration::from_millis(10), expires_in).await
+    }
+
+    #[tokio::test]
+    async fn test_approved() {
+        let http = stub_server(|hit| {
+            if hit < 2 {
+                r#"{"status":"pending"}"#
+            } else {
+                r#"{"status":"approved","token":"pat_123"}"#
+            }
+        });
+
+        assert_eq!(
+            poll(&http, Duration::from_secs(5)).await.unwrap(),
+            "pat_123"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_denied() {
+        let http = stub_server(|_| r#"{"status":"denied"}"#);
+
+        let error = poll(&http, Duration::from_secs(5)).await.unwrap_err();
+
+        assert_eq!(HopError::exit_code_of(&error), 4);
+        assert!(error.to_string().contains("denied"));
+    }
+
+    #[tokio::test]
+    async fn test_times_out() {
+        let http = stub_server(|_| r#"{"status":"pending"}"#);
+
+        let error = poll(&http, Duration::from_millis(100)).await.unwrap_err();
+
+        assert!(error.to_string().contains("expired"));
+    }
+}
@@ -1,7 +1,10 @@
+use anyhow::Result;
+
 use super::Options;
 use crate::state::http::HttpClient;
+use crate::utils::prompt::{self, Arg};
 
-pub async fn flags_login(options: Options, _http: HttpClient) -> String {
+pub async fn flags_login(options: Options, _http: HttpClient) -> Result<String> {
     match options {
         Options { token: Some(_), .. }
         | Options {
@@ -10,12 +13,9 @@ pub async fn flags_login(options: Options, _http: HttpClient) -> String {
             ..
         } => {
             if options.token.is_none() || options.token.as_ref().unwrap().is_empty() {
-                dialoguer::Password::new()
-                    .with_prompt("Token")
-                    .interact()
-                    .expect("Error getting token")
+                Ok(prompt::password("Token", Arg::new("token", "--token <TOKEN>"))?.interact()?)
             } else {
-                options.token.unwrap()
+                Ok(options.token.unwrap())
             }
         }
 
@@ -1,15 +1,21 @@
 mod browser_auth;
+mod device_auth;
 mod flags_auth;
 mod types;
 pub mod util;
 
 use anyhow::Result;
+use chrono::Utc;
 use clap::Parser;
 
 use self::browser_auth::browser_login;
+use self::device_auth::device_login;
 use self::flags_auth::flags_login;
+use self::util::TokenType;
+use crate::state::http::HttpClient;
 use crate::state::State;
 use crate::utils::in_path;
+use crate::utils::prompt;
 
 const WEB_AUTH_URL: &str = "https://console.hop.io/cli-auth";
 const PAT_FALLBACK_URL: &str = "https://console.hop.io/settings/pats";
@@ -22,6 +28,20 @@ pub struct Options {
         help = "Project Token or Personal Authorization Token, you can use `--token=` to take the token from stdin"
     )]
     token: Option<String>,
+
+    #[clap(
+        long,
+        help = "Login by confirming a code from any device, useful over SSH or in containers",
+        conflicts_with = "token"
+    )]
+    device: bool,
+
+    #[clap(
+        long,
+        help = "Do not open a browser, print the URL to open instead",
+        conflicts_with = "token"
+    )]
+    no_browser: bool,
     /*     #[clap(long, help = "Email")]
     email: Option<String>,
     #[clap(
@@ -32,12 +52,21 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let init_token = if Options::default() != options {
-        flags_login(options, state.http.clone()).await
+    let init_token = if options.token.is_some() {
+        flags_login(options, state.http.clone()).await?
     } else if let Ok(env_token) = std::env::var("TOKEN") {
         env_token
+    } else if options.device {
+        // the stored token should not be sent while logging in
+        let http = HttpClient::with_options(
+            None,
+            Some(state.http.base_url.clone()),
+            state.http.options.clone(),
+        );
+
+        device_login(&http, !options.no_browser).await?
     } else {
-        browser_login().await?
+        browser_login(!options.no_browser).await?
     };
 
     token(&init_token, state).await
@@ -69,14 +98,25 @@ pub async fn token(token: &str, mut state: State) -> Result<()> {
         .auth
         .authorized
         .insert(authorized.id.clone(), token.to_string());
+    state
+        .auth
+        .update_account(&authorized, TokenType::from_token(token).ok());
+    state
+        .auth
+        .accounts
+        .entry(authorized.id.clone())
+        .or_default()
+        .added_at
+        .get_or_insert_with(Utc::now);
     state.auth.save().await?;
 
-    if !state.is_ci
+    if prompt::is_interactive()
         && in_path("docker").await
-        && dialoguer::Confirm::new()
-            .with_prompt("Docker was detected, would you like to login to the Hop registry?")
-            .default(false)
-            .interact()?
+        && prompt::confirm(
+            "Docker was detected, would you like to login to the Hop registry?",
+            false,
+            "--no-input",
+        )?
     {
         super::docker::login_new(&authorized.email, token).await?;
     }
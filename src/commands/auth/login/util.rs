@@ -1,13 +1,15 @@
+use std::fmt::Display;
 use std::str::FromStr;
 
-use anyhow::{anyhow, Result};
-use serde::Deserialize;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
 
 use crate::commands::auth::types::{AuthorizedClient, UserMe};
 use crate::commands::projects::types::ThisProjectResponse;
+use crate::error::HopError;
 use crate::state::http::HttpClient;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum TokenType {
     #[serde(rename = "PAT")]
     Pat,
@@ -26,13 +28,26 @@ impl FromStr for TokenType {
     }
 }
 
+impl Display for TokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pat => write!(f, "PAT"),
+            Self::Ptk => write!(f, "PTK"),
+            Self::Bearer => write!(f, "BEARER"),
+        }
+    }
+}
+
 impl TokenType {
     pub fn from_token(token: &str) -> Result<Self> {
         Self::from_str(&token.split('_').next().unwrap_or("").to_uppercase())
     }
 }
 
-pub async fn token_options(http: HttpClient, token_type: Option<TokenType>) -> AuthorizedClient {
+pub async fn token_options(
+    http: HttpClient,
+    token_type: Option<TokenType>,
+) -> Result<AuthorizedClient> {
     match token_type {
         // bearer token works the same as pat
         Some(TokenType::Pat | TokenType::Bearer) => login_pat(http.clone()).await,
@@ -40,45 +55,43 @@ pub async fn token_options(http: HttpClient, token_type: Option<TokenType>) -> A
         // ptks only allow one project at a time so diff route
         Some(TokenType::Ptk) => login_ptk(http.clone()).await,
         // should be impossible to get here
-        token => {
-            panic!("Unsupported token type: {token:?}");
-        }
+        None => Err(HopError::Unauthorized("Missing token type".to_string()).into()),
     }
 }
 
-async fn login_pat(http: HttpClient) -> AuthorizedClient {
+async fn login_pat(http: HttpClient) -> Result<AuthorizedClient> {
     let response = http
         .request::<UserMe>("GET", "/users/@me", None)
         .await
-        .expect("Error logging in")
-        .expect("Error while parsing response");
+        .context("Error logging in")?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
 
-    AuthorizedClient {
+    Ok(AuthorizedClient {
         id: response.user.id,
         name: response.user.name,
         projects: response.projects,
         leap_token: response.leap_token,
         email: response.user.email,
         email_verified: response.user.email_verified,
-    }
+    })
 }
 
-async fn login_ptk(http: HttpClient) -> AuthorizedClient {
+async fn login_ptk(http: HttpClient) -> Result<AuthorizedClient> {
     let ThisProjectResponse {
         leap_token,
         project,
     } = http
         .request::<ThisProjectResponse>("GET", "/projects/@this", None)
         .await
-        .expect("Error logging in")
-        .expect("Error while parsing response");
+        .context("Error logging in")?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
 
-    AuthorizedClient {
+    Ok(AuthorizedClient {
         projects: vec![project.clone()],
         name: project.name,
         id: project.id,
         leap_token,
         email: "user@hop.io".to_string(),
         email_verified: true,
-    }
+    })
 }
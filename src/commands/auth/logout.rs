@@ -31,6 +31,7 @@ pub async fn handle(_options: Options, mut state: State) -> Result<()> {
 
     // remove the user from the store
     state.auth.authorized.remove(user_id.as_ref().unwrap());
+    state.auth.accounts.remove(user_id.as_ref().unwrap());
     state.auth.save().await?;
 
     log::info!("You have been logged out");
@@ -0,0 +1,60 @@
+use anyhow::{ensure, Result};
+use clap::Parser;
+
+use crate::state::State;
+use crate::store::credentials::CredentialStore;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Move the saved tokens to a different credential store")]
+pub struct Options {
+    #[clap(help = "Credential store to move to, `plaintext`, `encrypted` or `helper:<command>`")]
+    pub store: Option<CredentialStore>,
+}
+
+pub async fn handle(options: Options, mut state: State) -> Result<()> {
+    let store = match options.store {
+        Some(store) => store,
+
+        None => {
+            let values = CredentialStore::values();
+
+            let idx = prompt::select(
+                "Select a credential store",
+                &values,
+                0,
+                Arg::new("store", "<STORE>"),
+            )?;
+
+            if values[idx] == "helper" {
+                CredentialStore::Helper {
+                    command: prompt::input::<String>(
+                        "Credential helper command",
+                        Arg::new("store", "<STORE>"),
+                    )?
+                    .interact_text()?,
+                }
+            } else {
+                values[idx].parse()?
+            }
+        }
+    };
+
+    ensure!(
+        store != state.auth.store,
+        "The tokens are already in the {} store",
+        store
+    );
+
+    let previous = state.auth.store.clone();
+    let count = state.auth.authorized.len();
+
+    state.auth.migrate_store(store).await?;
+
+    log::info!(
+        "Moved {count} token(s) from the {previous} store to the {} store",
+        state.auth.store
+    );
+
+    Ok(())
+}
@@ -2,6 +2,8 @@ pub mod docker;
 mod list;
 pub mod login;
 mod logout;
+mod migrate_store;
+mod pats;
 mod switch;
 pub mod types;
 mod utils;
@@ -18,8 +20,12 @@ pub enum Commands {
     Login(login::Options),
     Logout(logout::Options),
     Switch(switch::Options),
+    #[clap(name = "migrate-store")]
+    MigrateStore(migrate_store::Options),
     #[clap(alias = "registry")]
     Docker(docker::Options),
+    #[clap(alias = "pat")]
+    Pats(pats::Options),
 }
 
 #[derive(Debug, Parser)]
@@ -34,10 +40,9 @@ pub async fn handle(options: Options, mut state: State) -> Result<()> {
         Commands::Login(options) => login::handle(options, state).await,
         Commands::Logout(options) => logout::handle(options, state).await,
         Commands::Switch(options) => switch::handle(options, state).await,
-        Commands::List(options) => {
-            list::handle(&options, &state);
-            Ok(())
-        }
+        Commands::MigrateStore(options) => migrate_store::handle(options, state).await,
+        Commands::List(options) => list::handle(options, state).await,
         Commands::Docker(options) => docker::handle(&options, &mut state).await,
+        Commands::Pats(options) => pats::handle(options, state).await,
     }
 }
@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Result};
+use clap::Parser;
+
+use super::utils::create_pat;
+use crate::state::State;
+use crate::utils::output::{print_token, TokenOutput};
+use crate::utils::parse_expiration;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Create a new personal access token")]
+pub struct Options {
+    #[clap(help = "Name of the token")]
+    name: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Expiration date of the token, can be a date or a duration (60s, 1d, 30d, 1y)",
+        value_parser = parse_expiration
+    )]
+    expiration: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the token",
+        value_enum,
+        default_value = "text"
+    )]
+    output: TokenOutput,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let name = match options.name {
+        Some(name) => name,
+        None => prompt::input::<String>("Name of the token", Arg::new("name", "<NAME>"))?
+            .interact_text()?,
+    };
+
+    let created = create_pat(&state.http, &name, options.expiration).await?;
+
+    let token = created
+        .token
+        .clone()
+        .ok_or_else(|| anyhow!("The token was created but not returned"))?;
+
+    // info logs go to stdout, keep it clean for `--output env` and `--output json`
+    if options.output == TokenOutput::Text {
+        log::info!("Created personal access token `{}` ({})", name, created.id);
+    }
+
+    print_token(options.output, &token, &created)
+}
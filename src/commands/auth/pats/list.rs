@@ -0,0 +1,32 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{format_pats, get_all_pats};
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "List all personal access tokens")]
+pub struct Options {
+    #[clap(short, long, help = "Only print the IDs of the tokens")]
+    quiet: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let pats = get_all_pats(&state.http).await?;
+
+    if options.quiet {
+        let ids = pats
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        println!("{ids}");
+    } else {
+        let pats_fmt = format_pats(&pats, true);
+
+        println!("{}", pats_fmt.join("\n"));
+    }
+
+    Ok(())
+}
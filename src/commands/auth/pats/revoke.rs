@@ -0,0 +1,66 @@
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+
+use super::utils::{format_pats, get_all_pats, revoke_pat};
+use crate::state::State;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Revoke personal access tokens")]
+pub struct Options {
+    #[clap(help = "IDs of the tokens")]
+    pats: Vec<String>,
+
+    #[clap(short, long, help = "Skip confirmation")]
+    force: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let pats = if !options.pats.is_empty() {
+        options.pats
+    } else {
+        let pats = get_all_pats(&state.http).await?;
+        ensure!(!pats.is_empty(), "No personal access tokens found");
+        let pats_fmt = format_pats(&pats, false);
+
+        let idxs = prompt::multi_select(
+            "Select the tokens to revoke",
+            &pats_fmt,
+            Arg::new("pats", "<PATS>"),
+        )?;
+
+        pats.iter()
+            .enumerate()
+            .filter(|(i, _)| idxs.contains(i))
+            .map(|(_, p)| p.id.clone())
+            .collect()
+    };
+
+    if !options.force
+        && !prompt::confirm(
+            format!(
+                "Are you sure you want to revoke {} personal access tokens?",
+                pats.len()
+            ),
+            false,
+            "--force",
+        )?
+    {
+        bail!("Aborted");
+    }
+
+    let mut revoke_count = 0;
+
+    for pat in &pats {
+        log::info!("Revoking personal access token `{pat}`");
+        revoke_pat(&state.http, pat).await?;
+        revoke_count += 1;
+    }
+
+    log::info!(
+        "Revoked {revoke_count}/{} personal access tokens",
+        pats.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Pat {
+    pub id: String,
+    pub name: Option<String>,
+    /// only sent in full when the token is created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub created_at: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePat {
+    pub name: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SinglePat {
+    pub pat: Pat,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultiplePats {
+    pub pats: Vec<Pat>,
+}
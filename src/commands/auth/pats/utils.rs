@@ -0,0 +1,67 @@
+use std::io::Write;
+
+use anyhow::{anyhow, Result};
+use tabwriter::TabWriter;
+
+use super::types::{CreatePat, MultiplePats, Pat, SinglePat};
+use crate::state::http::HttpClient;
+
+pub async fn create_pat(http: &HttpClient, name: &str, expires_at: Option<String>) -> Result<Pat> {
+    let data = serde_json::to_vec(&CreatePat {
+        name: name.to_string(),
+        expires_at,
+    })?;
+
+    let response = http
+        .request::<SinglePat>(
+            "POST",
+            "/users/@me/pats",
+            Some((data.into(), "application/json")),
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.pat)
+}
+
+pub async fn get_all_pats(http: &HttpClient) -> Result<Vec<Pat>> {
+    let response = http
+        .request::<MultiplePats>("GET", "/users/@me/pats", None)
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.pats)
+}
+
+pub async fn revoke_pat(http: &HttpClient, pat: &str) -> Result<()> {
+    http.request::<()>("DELETE", &format!("/users/@me/pats/{pat}"), None)
+        .await?;
+
+    Ok(())
+}
+
+pub fn format_pats(pats: &[Pat], title: bool) -> Vec<String> {
+    let mut tw = TabWriter::new(vec![]);
+
+    if title {
+        writeln!(tw, "ID\tNAME\tCREATION\tEXPIRATION").unwrap();
+    }
+
+    for pat in pats {
+        writeln!(
+            tw,
+            "{}\t{}\t{}\t{}",
+            pat.id,
+            pat.name.as_deref().unwrap_or("-"),
+            pat.created_at,
+            pat.expires_at.as_deref().unwrap_or("-"),
+        )
+        .unwrap();
+    }
+
+    String::from_utf8(tw.into_inner().unwrap())
+        .unwrap()
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect()
+}
@@ -3,14 +3,31 @@ use clap::Parser;
 
 use super::utils::format_users;
 use crate::config::EXEC_NAME;
+use crate::error::HopError;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Switch to a different user")]
-pub struct Options {}
+pub struct Options {
+    #[clap(help = "ID of the user to switch to")]
+    pub user: Option<String>,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    if let Some(user) = options.user {
+        let authorized = state
+            .auth
+            .authorized
+            .get(&user)
+            .cloned()
+            .ok_or_else(|| HopError::NotFound(format!("You are not logged in as `{user}`")))?;
+
+        return super::login::token(&authorized, state).await;
+    }
 
-pub async fn handle(_options: Options, state: State) -> Result<()> {
-    let users = state.auth.authorized.keys().collect::<Vec<_>>();
+    let mut users = state.auth.authorized.keys().collect::<Vec<_>>();
+    users.sort();
 
     ensure!(
         !users.is_empty(),
@@ -18,13 +35,9 @@ pub async fn handle(_options: Options, state: State) -> Result<()> {
         EXEC_NAME
     );
 
-    let users_fmt = format_users(&users, false);
+    let users_fmt = format_users(&users, &state.auth, None, false);
 
-    let idx = dialoguer::Select::new()
-        .with_prompt("Select a user")
-        .items(&users_fmt)
-        .default(0)
-        .interact()?;
+    let idx = prompt::select("Select a user", &users_fmt, 0, Arg::new("user", "<USER>"))?;
 
     let user_id = users.get(idx).unwrap().to_owned();
 
@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
 
 use crate::commands::projects::types::Project;
 
@@ -27,3 +29,23 @@ pub struct UserMe {
     pub user: User,
     pub projects: Vec<Project>,
 }
+
+/// Whether a stored token still works, see `hop auth ls --check`.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStatus {
+    Valid,
+    Revoked,
+    /// the check failed for another reason, like a network error
+    Unknown,
+}
+
+impl Display for TokenStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap().replace('"', "")
+        )
+    }
+}
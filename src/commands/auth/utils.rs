@@ -1,16 +1,60 @@
+use std::collections::HashMap;
 use std::io::Write;
 
 use tabwriter::TabWriter;
 
-pub fn format_users(users: &Vec<&String>, title: bool) -> Vec<String> {
+use super::types::TokenStatus;
+use crate::store::auth::Auth;
+use crate::utils::relative_time;
+
+const UNAVAILABLE_ELEMENT: &str = "-";
+
+pub fn format_users(
+    users: &[&String],
+    auth: &Auth,
+    statuses: Option<&HashMap<String, TokenStatus>>,
+    title: bool,
+) -> Vec<String> {
     let mut tw = TabWriter::new(vec![]);
 
     if title {
-        writeln!(&mut tw, "ID").unwrap();
+        write!(&mut tw, "ID\tNAME\tEMAIL\tTYPE\tADDED\tLAST USED").unwrap();
+
+        if statuses.is_some() {
+            write!(&mut tw, "\tSTATUS").unwrap();
+        }
+
+        writeln!(&mut tw).unwrap();
     }
 
     for user in users {
-        writeln!(&mut tw, "{user}").unwrap();
+        let account = auth.accounts.get(*user).cloned().unwrap_or_default();
+
+        write!(
+            &mut tw,
+            "{user}\t{}\t{}\t{}\t{}\t{}",
+            account.name.as_deref().unwrap_or(UNAVAILABLE_ELEMENT),
+            account.email.as_deref().unwrap_or(UNAVAILABLE_ELEMENT),
+            account
+                .token_type
+                .map_or_else(|| UNAVAILABLE_ELEMENT.to_string(), |t| t.to_string()),
+            format_date(account.added_at),
+            format_date(account.last_used),
+        )
+        .unwrap();
+
+        if let Some(statuses) = statuses {
+            write!(
+                &mut tw,
+                "\t{}",
+                statuses
+                    .get(*user)
+                    .map_or_else(|| UNAVAILABLE_ELEMENT.to_string(), ToString::to_string)
+            )
+            .unwrap();
+        }
+
+        writeln!(&mut tw).unwrap();
     }
 
     String::from_utf8(tw.into_inner().unwrap())
@@ -19,3 +63,10 @@ pub fn format_users(users: &Vec<&String>, title: bool) -> Vec<String> {
         .map(std::string::ToString::to_string)
         .collect()
 }
+
+fn format_date(date: Option<chrono::DateTime<chrono::Utc>>) -> String {
+    date.map_or_else(
+        || UNAVAILABLE_ELEMENT.to_string(),
+        |date| format!("{} ago", relative_time(date)),
+    )
+}
@@ -5,6 +5,7 @@ use serde_json::Value;
 use super::types::ChannelType;
 use crate::commands::channels::utils::create_channel;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 use crate::utils::validate_json_non_null;
 
 #[derive(Debug, Parser, Default, PartialEq, Eq)]
@@ -21,41 +22,35 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.clone().current_project_error().id;
+    let project_id = state.ctx.clone().current_project_error()?.id;
 
     let (type_, id, init_state) = if Options::default() == options {
-        let type_ = dialoguer::Select::new()
-            .with_prompt("Select a channel type")
-            .items(&ChannelType::variants())
-            .default(0)
-            .interact_opt()?
-            .ok_or_else(|| anyhow::anyhow!("No Channel type selected"))?;
+        let type_ = prompt::select(
+            "Select a channel type",
+            &ChannelType::variants(),
+            0,
+            Arg::new("channel type", "--type"),
+        )?;
 
         let type_ = ChannelType::variants()[type_].clone();
 
-        let id = if dialoguer::Confirm::new()
-            .with_prompt("Do you want to specify a custom Channel ID?")
-            .default(false)
-            .interact()?
-        {
-            Some(
-                dialoguer::Input::<String>::new()
-                    .with_prompt("Enter a custom ID")
-                    .interact()?,
-            )
+        let id = if prompt::confirm("Do you want to specify a custom Channel ID?", false, "--id")? {
+            Some(prompt::input::<String>("Enter a custom ID", Arg::new("id", "--id"))?.interact()?)
         } else {
             None
         };
 
-        let state = dialoguer::Input::new()
-            .with_prompt("Enter the initial state of the channel")
-            .default("{}".to_string())
-            .validate_with(|s: &String| -> Result<(), String> {
-                validate_json_non_null(s)
-                    .map(|_| ())
-                    .map_err(|e| e.to_string())
-            })
-            .interact()?;
+        let state = prompt::input(
+            "Enter the initial state of the channel",
+            Arg::new("state", "--state"),
+        )?
+        .default("{}".to_string())
+        .validate_with(|s: &String| -> Result<(), String> {
+            validate_json_non_null(s)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+        .interact()?;
 
         let state = serde_json::from_str(&state)?;
 
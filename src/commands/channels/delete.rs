@@ -1,9 +1,10 @@
-use anyhow::{anyhow, bail, ensure, Result};
+use anyhow::{bail, ensure, Result};
 use clap::Parser;
 
 use super::utils::delete_channel;
 use crate::commands::channels::utils::{format_channels, get_all_channels};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Delete Channels")]
@@ -16,7 +17,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let channels = if !options.channels.is_empty() {
         options.channels
@@ -25,11 +26,11 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         ensure!(!channels.is_empty(), "No Channels found");
         let channels_fmt = format_channels(&channels, false);
 
-        let idxs = dialoguer::MultiSelect::new()
-            .with_prompt("Select a Channel")
-            .items(&channels_fmt)
-            .interact_opt()?
-            .ok_or_else(|| anyhow!("No channel selected"))?;
+        let idxs = prompt::multi_select(
+            "Select a Channel",
+            &channels_fmt,
+            Arg::new("channels", "<CHANNELS>"),
+        )?;
 
         channels
             .iter()
@@ -40,14 +41,14 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     };
 
     if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt(format!(
+        && !prompt::confirm(
+            format!(
                 "Are you sure you want to delete {} Channels?",
                 channels.len()
-            ))
-            .default(false)
-            .interact_opt()?
-            .unwrap_or(false)
+            ),
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted");
     }
@@ -5,6 +5,7 @@ use super::types::EventOptions;
 use super::utils::{format_channels, get_all_channels, message_channel};
 use crate::commands::channels::utils::get_json_input;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser, Default, PartialEq, Eq)]
 #[clap(about = "Send a message to a Channel")]
@@ -17,7 +18,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let channel_id = if let Some(channel) = options.channel {
         channel
@@ -29,12 +30,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         );
         let channels_fmt = format_channels(&channels, false);
 
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a Channel")
-            .items(&channels_fmt)
-            .default(0)
-            .interact_opt()?
-            .ok_or_else(|| anyhow::anyhow!("No Channel selected"))?;
+        let idx = prompt::select(
+            "Select a Channel",
+            &channels_fmt,
+            0,
+            Arg::new("channel", "--channel"),
+        )?;
 
         channels.get(idx).unwrap().id.clone()
     };
@@ -50,14 +51,13 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
                 .map(|d| serde_json::from_str(&d).unwrap()),
         )
     } else {
-        let event_name = dialoguer::Input::<String>::new()
-            .with_prompt("Enter the event to send to the Channel")
-            .interact_text()?;
+        let event_name = prompt::input::<String>(
+            "Enter the event to send to the Channel",
+            Arg::new("event", "--event"),
+        )?
+        .interact_text()?;
 
-        let event_data = if dialoguer::Confirm::new()
-            .with_prompt("Do you want to specify event data?")
-            .default(false)
-            .interact()?
+        let event_data = if prompt::confirm("Do you want to specify event data?", false, "--data")?
         {
             Some(get_json_input()?)
         } else {
@@ -4,6 +4,7 @@ use clap::Parser;
 use super::utils::{format_channels, get_all_channels, subscribe_to_channel};
 use crate::commands::channels::tokens::utils::{format_tokens, get_all_tokens};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser, Default, PartialEq, Eq)]
 #[clap(about = "Subscribe a Leap Token to a Channel")]
@@ -19,7 +20,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let channel_id = if let Some(channel_id) = options.channel {
         channel_id
@@ -32,11 +33,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         );
         let channels_fmt = format_channels(&channels, false);
 
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a Channel")
-            .items(&channels_fmt)
-            .default(0)
-            .interact()?;
+        let idx = prompt::select(
+            "Select a Channel",
+            &channels_fmt,
+            0,
+            Arg::new("channel", "--channel"),
+        )?;
 
         channels[idx].id.clone()
     };
@@ -52,11 +54,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         );
         let tokens_fmt = format_tokens(&tokens, false);
 
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a Leap Token")
-            .items(&tokens_fmt)
-            .default(0)
-            .interact()?;
+        let idx = prompt::select(
+            "Select a Leap Token",
+            &tokens_fmt,
+            0,
+            Arg::new("token", "--token"),
+        )?;
 
         tokens[idx].id.clone()
     };
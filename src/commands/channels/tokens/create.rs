@@ -3,9 +3,9 @@ use clap::Parser;
 use serde_json::Value;
 
 use super::utils::create_token;
-use crate::commands::channels::tokens::utils::parse_expiration;
 use crate::state::State;
-use crate::utils::validate_json;
+use crate::utils::prompt::{self, Arg};
+use crate::utils::{parse_expiration, validate_json};
 
 #[derive(Debug, Parser, Default, PartialEq, Eq)]
 #[clap(about = "Create a new Leap Token")]
@@ -28,7 +28,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let (token_state, expires_at) = if options != Options::default() {
         (
@@ -36,17 +36,16 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
             options.expiration.map(|ex| parse_expiration(&ex).unwrap()),
         )
     } else {
-        let token_state = dialoguer::Input::<String>::new()
-            .with_prompt("State")
+        let token_state = prompt::input::<String>("State", Arg::new("state", "--state"))?
             .default("null".to_string())
             .validate_with(|s: &String| validate_json(s).map(|_| ()))
             .interact_text()?;
 
-        let expires_at = dialoguer::Input::<String>::new()
-            .with_prompt("Expiration date")
-            .default("0".to_string())
-            .validate_with(|s: &String| parse_expiration(s).map(|_| ()))
-            .interact_text()?;
+        let expires_at =
+            prompt::input::<String>("Expiration date", Arg::new("expiration", "--expiration"))?
+                .default("0".to_string())
+                .validate_with(|s: &String| parse_expiration(s).map(|_| ()))
+                .interact_text()?;
 
         (
             if token_state.to_lowercase() == "null" {
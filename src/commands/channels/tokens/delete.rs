@@ -1,8 +1,9 @@
-use anyhow::{anyhow, bail, ensure, Result};
+use anyhow::{bail, ensure, Result};
 use clap::Parser;
 
 use super::utils::{delete_token, format_tokens, get_all_tokens};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Delete Leap Tokens")]
@@ -15,7 +16,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let tokens = if !options.tokens.is_empty() {
         options.tokens
@@ -24,11 +25,11 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         ensure!(!tokens.is_empty(), "No Leap Tokens found");
         let tokens_fmt = format_tokens(&tokens, false);
 
-        let idxs = dialoguer::MultiSelect::new()
-            .with_prompt("Select a Leap Token")
-            .items(&tokens_fmt)
-            .interact_opt()?
-            .ok_or_else(|| anyhow!("No token selected"))?;
+        let idxs = prompt::multi_select(
+            "Select a Leap Token",
+            &tokens_fmt,
+            Arg::new("tokens", "<TOKENS>"),
+        )?;
 
         tokens
             .iter()
@@ -39,14 +40,14 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     };
 
     if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt(format!(
+        && !prompt::confirm(
+            format!(
                 "Are you sure you want to delete {} Leap Tokens?",
                 tokens.len()
-            ))
-            .default(false)
-            .interact_opt()?
-            .unwrap_or(false)
+            ),
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted");
     }
@@ -5,6 +5,7 @@ use super::utils::{format_tokens, get_all_tokens, message_token};
 use crate::commands::channels::types::EventOptions;
 use crate::commands::channels::utils::get_json_input;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser, Default, PartialEq, Eq)]
 #[clap(about = "Send a message to a Leap Token")]
@@ -17,7 +18,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let token_id = if let Some(token) = options.token {
         token
@@ -29,12 +30,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         );
         let channels_fmt = format_tokens(&tokens, false);
 
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a Leap Token")
-            .items(&channels_fmt)
-            .default(0)
-            .interact_opt()?
-            .ok_or_else(|| anyhow::anyhow!("No Leap Token selected"))?;
+        let idx = prompt::select(
+            "Select a Leap Token",
+            &channels_fmt,
+            0,
+            Arg::new("token", "--token"),
+        )?;
 
         tokens.get(idx).unwrap().id.clone()
     };
@@ -50,14 +51,11 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
                 .map(|d| serde_json::from_str(&d).unwrap()),
         )
     } else {
-        let event_name = dialoguer::Input::<String>::new()
-            .with_prompt("Enter the event name to send")
-            .interact_text()?;
+        let event_name =
+            prompt::input::<String>("Enter the event name to send", Arg::new("event", "--event"))?
+                .interact_text()?;
 
-        let event_data = if dialoguer::Confirm::new()
-            .with_prompt("Do you want to specify event data?")
-            .default(false)
-            .interact()?
+        let event_data = if prompt::confirm("Do you want to specify event data?", false, "--data")?
         {
             Some(get_json_input()?)
         } else {
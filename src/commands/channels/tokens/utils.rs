@@ -1,9 +1,6 @@
 use std::io::Write;
-use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
-use chrono::Utc;
-use ms::{__to_ms__, ms};
 use serde_json::Value;
 use tabwriter::TabWriter;
 
@@ -106,21 +103,3 @@ pub fn format_tokens(tokens: &[LeapToken], title: bool) -> Vec<String> {
         .map(std::string::ToString::to_string)
         .collect()
 }
-
-pub fn parse_expiration(expires_at: &str) -> Result<String> {
-    let now = chrono::Utc::now();
-
-    let date = if expires_at.split('-').count() == 3 || expires_at.split('/').count() == 3 {
-        chrono::DateTime::<Utc>::from_str(expires_at).map_err(|_| anyhow!("Invalid date format"))?
-    } else {
-        let relative = ms!(expires_at).ok_or_else(|| anyhow!("Invalid date format"))?;
-
-        now + chrono::Duration::milliseconds(relative as i64)
-    };
-
-    if date < now {
-        return Err(anyhow!("Expiration date must be in the future"));
-    }
-
-    Ok(date.to_rfc3339())
-}
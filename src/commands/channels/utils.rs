@@ -9,6 +9,7 @@ use super::types::{
 };
 use crate::config::DEFAULT_EDITOR;
 use crate::state::http::HttpClient;
+use crate::utils::prompt;
 
 pub async fn create_channel(
     http: &HttpClient,
@@ -164,7 +165,7 @@ pub fn get_json_input() -> Result<EventData> {
         .unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
 
     loop {
-        match dialoguer::Editor::new()
+        match prompt::editor("pass --data instead")?
             .executable(&editor_cmd)
             .extension(".json")
             .require_save(true)
@@ -2,13 +2,14 @@ use anyhow::{ensure, Result};
 use clap::Parser;
 
 use crate::commands::containers::utils::create_containers;
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Create containers for a deployment")]
 pub struct Options {
-    #[clap(short, long, help = "ID of the deployment")]
+    #[clap(short, long, help = "ID or name of the deployment")]
     deployment: Option<String>,
 
     #[clap(help = "Number of containers to create")]
@@ -16,31 +17,21 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment_id = match options.deployment {
-        Some(id) => id,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
-
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact()?;
-
-            deployments[idx].id.clone()
-        }
-    };
+    let deployment_id = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?
+    .id;
 
     let count = match options.count {
         Some(count) => count,
-        None => dialoguer::Input::<u64>::new()
-            .with_prompt("Number of containers to create")
-            .interact()?,
+        None => prompt::input::<u64>(
+            "Number of containers to create",
+            Arg::new("count", "<COUNT>"),
+        )?
+        .interact()?,
     };
 
     ensure!(count > 0, "Count must be greater than 0");
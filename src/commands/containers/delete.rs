@@ -3,8 +3,9 @@ use clap::Parser;
 
 use super::utils::delete_container;
 use crate::commands::containers::utils::{format_containers, get_all_containers};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Delete containers")]
@@ -20,26 +21,18 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     let containers = if !options.containers.is_empty() {
         options.containers
     } else {
-        let project_id = state.ctx.current_project_error().id;
+        let deployment =
+            resolve_deployment(&state, None, Arg::new("containers", "<CONTAINERS>")).await?;
 
-        let deployments = get_all_deployments(&state.http, &project_id).await?;
-        ensure!(!deployments.is_empty(), "No deployments found");
-        let deployments_fmt = format_deployments(&deployments, false);
-
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a deployment")
-            .items(&deployments_fmt)
-            .default(0)
-            .interact()?;
-
-        let containers = get_all_containers(&state.http, &deployments[idx].id).await?;
+        let containers = get_all_containers(&state.http, &deployment.id).await?;
         ensure!(!containers.is_empty(), "No containers found");
         let containers_fmt = format_containers(&containers, false);
 
-        let idxs = dialoguer::MultiSelect::new()
-            .with_prompt("Select containers to delete")
-            .items(&containers_fmt)
-            .interact()?;
+        let idxs = prompt::multi_select(
+            "Select containers to delete",
+            &containers_fmt,
+            Arg::new("containers", "<CONTAINERS>"),
+        )?;
 
         containers
             .iter()
@@ -50,13 +43,14 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     };
 
     if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt(format!(
+        && !prompt::confirm(
+            format!(
                 "Are you sure you want to delete {} containers?",
                 containers.len()
-            ))
-            .interact_opt()?
-            .unwrap_or(false)
+            ),
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted");
     }
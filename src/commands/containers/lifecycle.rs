@@ -0,0 +1,139 @@
+use std::future::Future;
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use futures_util::{stream, StreamExt};
+
+use super::types::ContainerState;
+use super::utils::{format_containers, get_all_containers};
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser, Clone)]
+pub struct TargetOptions {
+    #[clap(help = "IDs of the containers")]
+    pub containers: Vec<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "ID or name of the deployment to select containers from"
+    )]
+    pub deployment: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Only select containers in this state, requires `--deployment`",
+        requires = "deployment"
+    )]
+    pub state: Option<ContainerState>,
+
+    #[clap(
+        short,
+        long,
+        help = "Maximum amount of containers to update at once",
+        default_value = "5"
+    )]
+    pub concurrency: usize,
+}
+
+/// Resolves the container IDs to act on, either from the arguments,
+/// the deployment filter or by asking the user.
+pub async fn resolve_targets(options: &TargetOptions, state: &State) -> Result<Vec<String>> {
+    if !options.containers.is_empty() {
+        return Ok(options.containers.clone());
+    }
+
+    if let Some(ref deployment) = options.deployment {
+        let deployment = resolve_deployment(
+            state,
+            Some(deployment),
+            Arg::new("deployment", "--deployment"),
+        )
+        .await?;
+
+        let containers = get_all_containers(&state.http, &deployment.id)
+            .await?
+            .into_iter()
+            .filter(|c| options.state.as_ref().is_none_or(|s| &c.state == s))
+            .map(|c| c.id)
+            .collect::<Vec<_>>();
+
+        ensure!(!containers.is_empty(), "No matching containers found");
+
+        return Ok(containers);
+    }
+
+    let deployment = resolve_deployment(
+        state,
+        None,
+        Arg::new("containers", "<CONTAINERS> or --deployment"),
+    )
+    .await?;
+
+    let containers = get_all_containers(&state.http, &deployment.id).await?;
+    ensure!(!containers.is_empty(), "No containers found");
+    let containers_fmt = format_containers(&containers, false);
+
+    let idxs = prompt::multi_select(
+        "Select containers",
+        &containers_fmt,
+        Arg::new("containers", "<CONTAINERS> or --deployment"),
+    )?;
+
+    ensure!(!idxs.is_empty(), "No containers selected");
+
+    Ok(containers
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| idxs.contains(i))
+        .map(|(_, c)| c.id.clone())
+        .collect())
+}
+
+/// Runs `action` for every container with at most `concurrency` running at
+/// once, printing the result of each one as it finishes.
+pub async fn run_for_each<F, Fut>(
+    containers: Vec<String>,
+    concurrency: usize,
+    verb: &str,
+    action: F,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<String>>,
+{
+    let total = containers.len();
+
+    let mut results = stream::iter(containers)
+        .map(|container| {
+            let fut = action(container.clone());
+
+            async move { (container, fut.await) }
+        })
+        .buffer_unordered(concurrency.max(1));
+
+    let mut success = 0;
+
+    while let Some((container, result)) = results.next().await {
+        match result {
+            Ok(message) => {
+                log::info!("{message}");
+                success += 1;
+            }
+            Err(err) => log::error!("Failed to {verb} container `{container}`: {err}"),
+        }
+    }
+
+    ensure!(
+        success == total,
+        "Failed to {verb} {}/{total} containers",
+        total - success
+    );
+
+    log::info!("Finished, {success}/{total} containers affected");
+
+    Ok(())
+}
@@ -1,52 +1,116 @@
+use std::time::Duration;
+
 use anyhow::{ensure, Result};
+use chrono::Utc;
 use clap::Parser;
 
-use crate::commands::containers::utils::{format_containers, get_all_containers};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments, get_deployment};
+use crate::commands::containers::types::{Container, ContainerSort, ContainerState};
+use crate::commands::containers::utils::{
+    format_containers, format_project_containers, get_all_containers, get_all_project_containers,
+};
+use crate::commands::ignite::utils::{get_all_deployments, resolve_deployment};
 use crate::state::State;
+use crate::utils::parse_duration;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "List all containers")]
 pub struct Options {
-    #[clap(help = "ID of the deployment")]
+    #[clap(help = "ID or name of the deployment")]
     pub deployment: Option<String>,
 
     #[clap(short, long, help = "Only print the IDs of the deployments")]
     pub quiet: bool,
+
+    #[clap(
+        short,
+        long,
+        help = "List containers of every deployment in the project",
+        conflicts_with = "deployment"
+    )]
+    pub all: bool,
+
+    #[clap(
+        long = "deployment",
+        help = "Only show containers of deployments with this name, requires `--all`",
+        requires = "all"
+    )]
+    pub deployment_name: Option<String>,
+
+    #[clap(short, long, help = "Only show containers in this state")]
+    pub state: Option<ContainerState>,
+
+    #[clap(short, long, help = "Only show containers in this region")]
+    pub region: Option<String>,
+
+    #[clap(
+        long,
+        help = "Only show containers started longer ago than this, e.g. `1h`",
+        value_parser = parse_duration
+    )]
+    pub older_than: Option<Duration>,
+
+    #[clap(long, help = "Sort the containers by `uptime` or `state`")]
+    pub sort: Option<ContainerSort>,
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment = match options.deployment {
-        Some(id) => get_deployment(&state.http, &id).await?,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
+    let mut containers = if options.all {
+        let project_id = state.ctx.current_project_error()?.id;
 
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
+        let deployments = get_all_deployments(&state.http, &project_id)
+            .await?
+            .into_iter()
+            .filter(|d| {
+                options
+                    .deployment_name
+                    .as_ref()
+                    .is_none_or(|name| d.name.to_lowercase() == name.to_lowercase())
+            })
+            .collect::<Vec<_>>();
+        ensure!(!deployments.is_empty(), "No deployments found");
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact()?;
+        get_all_project_containers(&state.http, &deployments).await?
+    } else {
+        let deployment = resolve_deployment(
+            &state,
+            options.deployment.as_deref(),
+            Arg::new("deployment", "<DEPLOYMENT>"),
+        )
+        .await?;
 
-            deployments[idx].clone()
-        }
+        get_all_containers(&state.http, &deployment.id)
+            .await?
+            .into_iter()
+            .map(|c| (deployment.name.clone(), c))
+            .collect()
     };
 
-    let containers = get_all_containers(&state.http, &deployment.id).await?;
+    containers.retain(|(_, c)| matches_filters(&options, c));
+
+    match options.sort {
+        Some(ContainerSort::Uptime) => {
+            // oldest start first, containers that never started last
+            containers.sort_by_key(|(_, c)| (last_start(c).is_none(), last_start(c)))
+        }
+        Some(ContainerSort::State) => containers.sort_by_key(|(_, c)| c.state.to_string()),
+        None => {}
+    }
 
     if options.quiet {
         let ids = containers
             .iter()
-            .map(|d| d.id.as_str())
+            .map(|(_, c)| c.id.as_str())
             .collect::<Vec<_>>()
             .join(" ");
 
         println!("{ids}");
+    } else if options.all {
+        let containers_fmt = format_project_containers(&containers, true);
+
+        println!("{}", containers_fmt.join("\n"));
     } else {
+        let containers = containers.into_iter().map(|(_, c)| c).collect();
         let containers_fmt = format_containers(&containers, true);
 
         println!("{}", containers_fmt.join("\n"));
@@ -54,3 +118,35 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
 
     Ok(())
 }
+
+fn matches_filters(options: &Options, container: &Container) -> bool {
+    if let Some(ref state) = options.state {
+        if &container.state != state {
+            return false;
+        }
+    }
+
+    if let Some(ref region) = options.region {
+        if container.region.to_lowercase() != region.to_lowercase() {
+            return false;
+        }
+    }
+
+    if let Some(older_than) = options.older_than {
+        let Some(started) = last_start(container) else {
+            return false;
+        };
+
+        let age = (Utc::now() - started).to_std().unwrap_or_default();
+
+        if age < older_than {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn last_start(container: &Container) -> Option<chrono::DateTime<Utc>> {
+    container.uptime.as_ref().and_then(|u| u.last_start)
+}
@@ -7,11 +7,12 @@ use tokio::fs;
 use tokio::process::Command;
 
 use super::utils::{format_containers, format_logs, get_all_containers, get_container_logs};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::config::DEFAULT_EDITOR;
 use crate::state::State;
 use crate::utils::arisu::{ArisuClient, ArisuMessage};
 use crate::utils::in_path;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Get logs of a container")]
@@ -45,27 +46,19 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         Some(id) => id,
 
         None => {
-            let project_id = state.ctx.clone().current_project_error().id;
+            let deployment =
+                resolve_deployment(&state, None, Arg::new("container", "<CONTAINER>")).await?;
 
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact()?;
-
-            let containers = get_all_containers(&state.http, &deployments[idx].id).await?;
+            let containers = get_all_containers(&state.http, &deployment.id).await?;
             ensure!(!containers.is_empty(), "No containers found");
             let containers_fmt = format_containers(&containers, false);
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a container")
-                .default(0)
-                .items(&containers_fmt)
-                .interact()?;
+            let idx = prompt::select(
+                "Select a container",
+                &containers_fmt,
+                0,
+                Arg::new("container", "<CONTAINER>"),
+            )?;
 
             containers[idx].id.clone()
         }
@@ -1,7 +1,12 @@
 mod create;
 mod delete;
+mod lifecycle;
 mod list;
 mod logs;
+mod recreate;
+mod restart;
+mod start;
+mod stop;
 pub mod types;
 pub mod utils;
 
@@ -21,6 +26,10 @@ pub enum Commands {
 
     #[clap(name = "logs", alias = "log")]
     Log(logs::Options),
+    Start(start::Options),
+    Stop(stop::Options),
+    Restart(restart::Options),
+    Recreate(recreate::Options),
 }
 
 #[derive(Debug, Parser)]
@@ -36,5 +45,9 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         Commands::Delete(options) => delete::handle(options, state).await,
         Commands::List(options) => list::handle(options, state).await,
         Commands::Log(options) => logs::handle(options, state).await,
+        Commands::Start(options) => start::handle(options, state).await,
+        Commands::Stop(options) => stop::handle(options, state).await,
+        Commands::Restart(options) => restart::handle(options, state).await,
+        Commands::Recreate(options) => recreate::handle(options, state).await,
     }
 }
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+
+use super::lifecycle::{resolve_targets, run_for_each, TargetOptions};
+use super::types::ContainerState;
+use super::utils::{create_containers, delete_container, get_container, wait_for_container};
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Delete containers and replace them with new ones")]
+pub struct Options {
+    #[clap(flatten)]
+    pub targets: TargetOptions,
+
+    #[clap(
+        long,
+        help = "Seconds to wait for each replacement to be running",
+        default_value = "300"
+    )]
+    pub timeout: u64,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let containers = resolve_targets(&options.targets, &state).await?;
+    let http = &state.http;
+    let timeout = Duration::from_secs(options.timeout);
+
+    run_for_each(
+        containers,
+        options.targets.concurrency,
+        "recreate",
+        |container| async move {
+            let old = get_container(http, &container).await?;
+
+            delete_container(http, &old.id).await?;
+
+            let new = create_containers(http, &old.deployment_id, 1)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow!("No replacement container was created"))?;
+
+            wait_for_container(http, &new.id, timeout, |c| {
+                c.state == ContainerState::Running
+            })
+            .await?;
+
+            Ok(format!("Recreated container `{container}` as `{}`", new.id))
+        },
+    )
+    .await
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+
+use super::lifecycle::{resolve_targets, run_for_each, TargetOptions};
+use super::types::ContainerState;
+use super::utils::{update_container_state, wait_for_container};
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Restart containers")]
+pub struct Options {
+    #[clap(flatten)]
+    pub targets: TargetOptions,
+
+    #[clap(
+        long,
+        help = "Seconds to wait for each container to stop",
+        default_value = "120"
+    )]
+    pub timeout: u64,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let containers = resolve_targets(&options.targets, &state).await?;
+    let http = &state.http;
+    let timeout = Duration::from_secs(options.timeout);
+
+    run_for_each(
+        containers,
+        options.targets.concurrency,
+        "restart",
+        |container| async move {
+            update_container_state(http, &container, ContainerState::Stopped).await?;
+
+            wait_for_container(http, &container, timeout, |c| c.has_exited()).await?;
+
+            update_container_state(http, &container, ContainerState::Running).await?;
+
+            Ok(format!("Restarted container `{container}`"))
+        },
+    )
+    .await
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::lifecycle::{resolve_targets, run_for_each, TargetOptions};
+use super::types::ContainerState;
+use super::utils::update_container_state;
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Stop containers")]
+pub struct Options {
+    #[clap(flatten)]
+    pub targets: TargetOptions,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let containers = resolve_targets(&options.targets, &state).await?;
+    let http = &state.http;
+
+    run_for_each(
+        containers,
+        options.targets.concurrency,
+        "stop",
+        |container| async move {
+            update_container_state(http, &container, ContainerState::Stopped).await?;
+
+            Ok(format!("Stopped container `{container}`"))
+        },
+    )
+    .await
+}
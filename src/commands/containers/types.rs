@@ -6,6 +6,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::commands::ignite::types::Deployment;
+use crate::utils::resolve::Resource;
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
@@ -45,6 +46,14 @@ pub enum ContainerState {
     Failed,
 }
 
+impl FromStr for ContainerState {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        serde_json::from_str(&format!("\"{}\"", s.to_lowercase())).map_err(|e| anyhow!(e))
+    }
+}
+
 impl Display for ContainerState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -55,11 +64,26 @@ impl Display for ContainerState {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerSort {
+    Uptime,
+    State,
+}
+
+impl FromStr for ContainerSort {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        serde_json::from_str(&format!("\"{}\"", s.to_lowercase())).map_err(|e| anyhow!(e))
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Uptime {
     pub last_start: Option<DateTime<Utc>>,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Container {
     pub id: String,
     pub created_at: String,
@@ -70,6 +94,34 @@ pub struct Container {
     pub uptime: Option<Uptime>,
     #[serde(rename = "type")]
     pub type_: ContainerType,
+    /// only present once the container has exited
+    pub exit_code: Option<i32>,
+}
+
+impl Resource for Container {
+    const KIND: &'static str = "container";
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Container {
+    pub fn has_exited(&self) -> bool {
+        matches!(
+            self.state,
+            ContainerState::Exited | ContainerState::Failed | ContainerState::Stopped
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SingleContainer {
+    pub container: Container,
 }
 
 #[derive(Debug, Deserialize)]
@@ -87,19 +139,32 @@ pub struct ContainerOptions {
 
 impl ContainerOptions {
     pub fn from_deployment(deployment: &Deployment) -> Self {
+        let autoscaling = deployment.config.autoscaling.as_ref();
+
         Self {
             containers: Some(deployment.container_count),
-            min_containers: Some(0),
-            max_containers: Some(0),
-            // min_containers: Some(deployment.config.resources.min_containers as u64),
-            // max_containers: Some(deployment.config.resources.max_containers as u64),
+            min_containers: autoscaling.map(|a| a.min_containers),
+            max_containers: autoscaling.map(|a| a.max_containers),
         }
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct UpdateContainerState {
+    pub preferred_state: ContainerState,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CreateContainers {
     pub count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<ContainerOverrides>,
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ContainerOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
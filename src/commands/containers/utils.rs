@@ -1,28 +1,44 @@
 use std::borrow::Borrow;
 use std::io::Write;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use console::style;
+use futures_util::{stream, StreamExt};
 use serde_json::Value;
 use tabwriter::TabWriter;
 
 use super::types::{
-    Container, ContainerState, CreateContainers, Log, LogsResponse, MultipleContainersResponse,
+    Container, ContainerOverrides, ContainerState, CreateContainers, Log, LogsResponse,
+    MultipleContainersResponse, SingleContainer, UpdateContainerState,
 };
+use crate::commands::ignite::types::Deployment;
+use crate::error::HopError;
 use crate::state::http::HttpClient;
 use crate::utils::relative_time;
 
+const FETCH_CONCURRENCY: usize = 5;
+
 pub async fn create_containers(
     http: &HttpClient,
     deployment_id: &str,
     count: u64,
+) -> Result<Vec<Container>> {
+    create_containers_with_overrides(http, deployment_id, count, None).await
+}
+
+pub async fn create_containers_with_overrides(
+    http: &HttpClient,
+    deployment_id: &str,
+    count: u64,
+    overrides: Option<ContainerOverrides>,
 ) -> Result<Vec<Container>> {
     let response = http
         .request::<MultipleContainersResponse>(
             "POST",
             &format!("/ignite/deployments/{deployment_id}/containers"),
             Some((
-                serde_json::to_vec(&CreateContainers { count })
+                serde_json::to_vec(&CreateContainers { count, overrides })
                     .unwrap()
                     .into(),
                 "application/json",
@@ -34,6 +50,15 @@ pub async fn create_containers(
     Ok(response.containers)
 }
 
+pub async fn get_container(http: &HttpClient, container_id: &str) -> Result<Container> {
+    let response = http
+        .request::<SingleContainer>("GET", &format!("/ignite/containers/{container_id}"), None)
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.container)
+}
+
 pub async fn delete_container(http: &HttpClient, container_id: &str) -> Result<()> {
     http.request::<Value>(
         "DELETE",
@@ -45,6 +70,57 @@ pub async fn delete_container(http: &HttpClient, container_id: &str) -> Result<(
     Ok(())
 }
 
+pub async fn update_container_state(
+    http: &HttpClient,
+    container_id: &str,
+    preferred_state: ContainerState,
+) -> Result<()> {
+    http.request::<Value>(
+        "PUT",
+        &format!("/ignite/containers/{container_id}/state"),
+        Some((
+            serde_json::to_vec(&UpdateContainerState { preferred_state })
+                .unwrap()
+                .into(),
+            "application/json",
+        )),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Polls the container until `predicate` matches or `timeout` passes.
+pub async fn wait_for_container<F>(
+    http: &HttpClient,
+    container_id: &str,
+    timeout: Duration,
+    predicate: F,
+) -> Result<Container>
+where
+    F: Fn(&Container) -> bool,
+{
+    let started = tokio::time::Instant::now();
+
+    loop {
+        let container = get_container(http, container_id).await?;
+
+        if predicate(&container) {
+            return Ok(container);
+        }
+
+        if started.elapsed() >= timeout {
+            return Err(HopError::Timeout(format!(
+                "Timed out waiting for container `{container_id}`, last state was `{}`",
+                container.state
+            ))
+            .into());
+        }
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+    }
+}
+
 pub async fn get_all_containers(http: &HttpClient, deployment_id: &str) -> Result<Vec<Container>> {
     let response = http
         .request::<MultipleContainersResponse>(
@@ -58,6 +134,33 @@ pub async fn get_all_containers(http: &HttpClient, deployment_id: &str) -> Resul
     Ok(response.containers)
 }
 
+/// Fetches the containers of every deployment concurrently, paired with the
+/// name of the deployment they belong to.
+pub async fn get_all_project_containers(
+    http: &HttpClient,
+    deployments: &[Deployment],
+) -> Result<Vec<(String, Container)>> {
+    let results = stream::iter(deployments)
+        .map(|deployment| async move {
+            get_all_containers(http, &deployment.id)
+                .await
+                .map(|containers| (deployment.name.clone(), containers))
+        })
+        .buffer_unordered(FETCH_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut containers = vec![];
+
+    for result in results {
+        let (name, deployment_containers) = result?;
+
+        containers.extend(deployment_containers.into_iter().map(|c| (name.clone(), c)));
+    }
+
+    Ok(containers)
+}
+
 pub async fn get_container_logs(
     http: &HttpClient,
     container_id: &str,
@@ -99,19 +202,7 @@ pub fn format_containers(containers: &Vec<Container>, title: bool) -> Vec<String
                 .as_ref()
                 .map(|ip| ip.borrow())
                 .unwrap_or_else(|| UNAVAILABLE_ELEMENT),
-            if container.state != ContainerState::Running {
-                UNAVAILABLE_ELEMENT.to_string()
-            } else {
-                container
-                    .uptime
-                    .as_ref()
-                    .map(|u| {
-                        u.last_start
-                            .map(relative_time)
-                            .unwrap_or_else(|| UNAVAILABLE_ELEMENT.to_string())
-                    })
-                    .unwrap_or_else(|| UNAVAILABLE_ELEMENT.to_string())
-            },
+            format_uptime(container),
         )
         .unwrap();
     }
@@ -123,6 +214,56 @@ pub fn format_containers(containers: &Vec<Container>, title: bool) -> Vec<String
         .collect()
 }
 
+/// Same as `format_containers` but with the name of the deployment each
+/// container belongs to.
+pub fn format_project_containers(containers: &[(String, Container)], title: bool) -> Vec<String> {
+    let mut tw = TabWriter::new(vec![]);
+
+    if title {
+        writeln!(tw, "ID\tDEPLOYMENT\tREGION\tSTATE\tINTERNAL IP\tUPTIME").unwrap();
+    }
+
+    for (deployment, container) in containers {
+        writeln!(
+            tw,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            container.id,
+            deployment,
+            container.region,
+            container.state,
+            container
+                .internal_ip
+                .as_ref()
+                .map(|ip| ip.borrow())
+                .unwrap_or_else(|| UNAVAILABLE_ELEMENT),
+            format_uptime(container),
+        )
+        .unwrap();
+    }
+
+    String::from_utf8(tw.into_inner().unwrap())
+        .unwrap()
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect()
+}
+
+fn format_uptime(container: &Container) -> String {
+    if container.state != ContainerState::Running {
+        UNAVAILABLE_ELEMENT.to_string()
+    } else {
+        container
+            .uptime
+            .as_ref()
+            .map(|u| {
+                u.last_start
+                    .map(relative_time)
+                    .unwrap_or_else(|| UNAVAILABLE_ELEMENT.to_string())
+            })
+            .unwrap_or_else(|| UNAVAILABLE_ELEMENT.to_string())
+    }
+}
+
 pub fn format_logs(log: &[Log], colors: bool, timestamps: bool, details: bool) -> Vec<String> {
     log.iter()
         .map(|log| format_log(log, colors, timestamps, details))
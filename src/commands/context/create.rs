@@ -0,0 +1,64 @@
+use anyhow::{ensure, Result};
+use clap::Parser;
+
+use crate::error::HopError;
+use crate::state::State;
+use crate::store::context::{Context, NamedContext};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Create a new context, unset values default to the ones in use")]
+pub struct Options {
+    #[clap(help = "Name of the context")]
+    pub name: String,
+
+    #[clap(long, help = "ID of the user to use")]
+    pub user: Option<String>,
+
+    #[clap(long, help = "URL of the Hop API")]
+    pub api_url: Option<String>,
+
+    #[clap(long, help = "URL of the Hop builder")]
+    pub builder_url: Option<String>,
+
+    #[clap(long, help = "Leap project used for live updates")]
+    pub leap_project: Option<String>,
+
+    #[clap(long, help = "URL of the Leap websocket")]
+    pub leap_ws_url: Option<String>,
+
+    #[clap(long, help = "Start using the context right away")]
+    pub r#use: bool,
+}
+
+pub async fn handle(options: Options, mut state: State) -> Result<()> {
+    Context::validate_context_name(&options.name)?;
+
+    ensure!(
+        !state.ctx.contexts.contains_key(&options.name),
+        HopError::Conflict(format!("Context `{}` already exists", options.name))
+    );
+
+    let named = NamedContext {
+        user: options.user.or_else(|| state.ctx.default_user.clone()),
+        // the global `--project` flag sets the project of the new context
+        project: state.ctx.project_override.clone(),
+        api_url: options
+            .api_url
+            .or_else(|| state.ctx.override_api_url.clone()),
+        builder_url: options.builder_url,
+        leap_project: options.leap_project,
+        leap_ws_url: options.leap_ws_url,
+    };
+
+    state.ctx.contexts.insert(options.name.clone(), named);
+
+    if options.r#use {
+        state.ctx.current_context = Some(options.name.clone());
+    }
+
+    state.ctx.save().await?;
+
+    log::info!("Context `{}` created", options.name);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::prompt;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Delete a context")]
+pub struct Options {
+    #[clap(help = "Name of the context to delete")]
+    pub name: String,
+
+    #[clap(short, long, help = "Skip confirmation")]
+    pub force: bool,
+}
+
+pub async fn handle(options: Options, mut state: State) -> Result<()> {
+    if !state.ctx.contexts.contains_key(&options.name) {
+        bail!(HopError::NotFound(format!(
+            "Context `{}` does not exist",
+            options.name
+        )));
+    }
+
+    if !options.force
+        && !prompt::confirm(
+            format!(
+                "Are you sure you want to delete context `{}`?",
+                options.name
+            ),
+            false,
+            "--force",
+        )?
+    {
+        bail!("Aborted");
+    }
+
+    state.ctx.contexts.remove(&options.name);
+
+    if state.ctx.current_context.as_ref() == Some(&options.name) {
+        state.ctx.current_context = None;
+    }
+
+    if state.ctx.active_context.as_ref() == Some(&options.name) {
+        state.ctx.leave_context();
+    }
+
+    state.ctx.save().await?;
+
+    log::info!("Context `{}` deleted", options.name);
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use clap::Parser;
+
+use super::utils::format_contexts;
+use crate::config::EXEC_NAME;
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "List all contexts, the one in use is marked with `*`")]
+pub struct Options {
+    #[clap(short, long, help = "Only print the names of the contexts")]
+    pub quiet: bool,
+}
+
+pub fn handle(options: &Options, state: &State) {
+    if options.quiet {
+        let names = state
+            .ctx
+            .contexts
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        println!("{names}");
+    } else if state.ctx.contexts.is_empty() {
+        log::info!("No contexts found, create one with `{EXEC_NAME} context create`");
+    } else {
+        println!("{}", format_contexts(&state.ctx, true).join("\n"));
+    }
+}
@@ -0,0 +1,44 @@
+mod create;
+mod delete;
+mod list;
+mod rename;
+mod use_context;
+mod utils;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::state::State;
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    #[clap(name = "ls", alias = "list")]
+    List(list::Options),
+    #[clap(name = "use", alias = "switch")]
+    Use(use_context::Options),
+    #[clap(name = "create", alias = "new")]
+    Create(create::Options),
+    Rename(rename::Options),
+    #[clap(name = "rm", alias = "delete")]
+    Delete(delete::Options),
+}
+
+#[derive(Debug, Parser)]
+#[clap(about = "Manage named contexts")]
+pub struct Options {
+    #[clap(subcommand)]
+    pub commands: Commands,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    match options.commands {
+        Commands::List(options) => {
+            list::handle(&options, &state);
+            Ok(())
+        }
+        Commands::Use(options) => use_context::handle(options, state).await,
+        Commands::Create(options) => create::handle(options, state).await,
+        Commands::Rename(options) => rename::handle(options, state).await,
+        Commands::Delete(options) => delete::handle(options, state).await,
+    }
+}
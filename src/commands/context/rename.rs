@@ -0,0 +1,54 @@
+use anyhow::{ensure, Result};
+use clap::Parser;
+
+use crate::error::HopError;
+use crate::state::State;
+use crate::store::context::Context;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Rename a context")]
+pub struct Options {
+    #[clap(help = "Current name of the context")]
+    pub name: String,
+
+    #[clap(help = "New name of the context")]
+    pub new_name: String,
+}
+
+pub async fn handle(options: Options, mut state: State) -> Result<()> {
+    Context::validate_context_name(&options.new_name)?;
+
+    ensure!(
+        !state.ctx.contexts.contains_key(&options.new_name),
+        HopError::Conflict(format!("Context `{}` already exists", options.new_name))
+    );
+
+    let named =
+        state.ctx.contexts.remove(&options.name).ok_or_else(|| {
+            HopError::NotFound(format!("Context `{}` does not exist", options.name))
+        })?;
+
+    state.ctx.contexts.insert(options.new_name.clone(), named);
+
+    for name in [
+        &mut state.ctx.current_context,
+        &mut state.ctx.active_context,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        if *name == options.name {
+            *name = options.new_name.clone();
+        }
+    }
+
+    state.ctx.save().await?;
+
+    log::info!(
+        "Context `{}` renamed to `{}`",
+        options.name,
+        options.new_name
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anyhow::{ensure, Result};
+use clap::Parser;
+
+use crate::config::EXEC_NAME;
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Set the context used by default")]
+pub struct Options {
+    #[clap(help = "Name of the context to use")]
+    pub name: Option<String>,
+
+    #[clap(
+        long,
+        help = "Stop using a context and go back to the top level settings",
+        conflicts_with = "name"
+    )]
+    pub unset: bool,
+}
+
+pub async fn handle(options: Options, mut state: State) -> Result<()> {
+    if options.unset {
+        state.ctx.current_context = None;
+        state.ctx.save().await?;
+
+        log::info!("No context is in use anymore");
+
+        return Ok(());
+    }
+
+    let name = match options.name {
+        Some(name) => {
+            ensure!(
+                state.ctx.contexts.contains_key(&name),
+                HopError::NotFound(format!(
+                    "Context `{name}` does not exist, run `{EXEC_NAME} context ls` to see all contexts"
+                ))
+            );
+
+            name
+        }
+
+        None => {
+            let names = state.ctx.contexts.keys().cloned().collect::<Vec<_>>();
+
+            ensure!(
+                !names.is_empty(),
+                "No contexts found, create one with `{EXEC_NAME} context create`"
+            );
+
+            let idx = prompt::select(
+                "Select a context",
+                &names,
+                state
+                    .ctx
+                    .current_context
+                    .as_ref()
+                    .and_then(|current| names.iter().position(|n| n == current))
+                    .unwrap_or(0),
+                Arg::new("context", "<NAME>"),
+            )?;
+
+            names[idx].clone()
+        }
+    };
+
+    state.ctx.current_context = Some(name.clone());
+    state.ctx.save().await?;
+
+    log::info!("Now using context `{name}`");
+
+    Ok(())
+}
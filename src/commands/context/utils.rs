@@ -0,0 +1,36 @@
+use std::io::Write;
+
+use tabwriter::TabWriter;
+
+use crate::store::context::Context;
+
+pub fn format_contexts(ctx: &Context, title: bool) -> Vec<String> {
+    let mut tw = TabWriter::new(vec![]);
+
+    if title {
+        writeln!(&mut tw, "NAME\tUSER\tPROJECT\tAPI URL").unwrap();
+    }
+
+    for (name, context) in &ctx.contexts {
+        let marker = if ctx.active_context.as_ref() == Some(name) {
+            "*"
+        } else {
+            " "
+        };
+
+        writeln!(
+            &mut tw,
+            "{marker} {name}\t{}\t{}\t{}",
+            context.user.as_deref().unwrap_or("-"),
+            context.project.as_deref().unwrap_or("-"),
+            context.api_url.as_deref().unwrap_or("-"),
+        )
+        .unwrap();
+    }
+
+    String::from_utf8(tw.into_inner().unwrap())
+        .unwrap()
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect()
+}
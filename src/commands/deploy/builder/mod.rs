@@ -32,7 +32,7 @@ pub async fn build(
 
     log::info!("Uploading...");
 
-    let build = builder_post(&state.http, deployment_id, bytes).await?;
+    let build = builder_post(&state.http, &state.ctx.builder_url(), deployment_id, bytes).await?;
 
     let (tx, mut rx) = unbounded_channel();
 
@@ -11,11 +11,15 @@ use tokio::io::AsyncWriteExt;
 use tokio_tar::Builder as TarBuilder;
 
 use super::types::{Build, SingleBuild};
-use crate::commands::deploy::HOP_BUILD_BASE_URL;
 use crate::state::http::HttpClient;
 use crate::store::hopfile::VALID_HOP_FILENAMES;
 
-pub async fn builder_post(http: &HttpClient, deployment_id: &str, bytes: Vec<u8>) -> Result<Build> {
+pub async fn builder_post(
+    http: &HttpClient,
+    builder_uri: &str,
+    deployment_id: &str,
+    bytes: Vec<u8>,
+) -> Result<Build> {
     let multipart = Form::new().part(
         "file",
         Part::bytes(bytes)
@@ -23,9 +27,6 @@ pub async fn builder_post(http: &HttpClient, deployment_id: &str, bytes: Vec<u8>
             .mime_str("application/x-gzip")?,
     );
 
-    let builder_uri =
-        std::env::var("BUILDER_URL").unwrap_or_else(|_| HOP_BUILD_BASE_URL.to_string());
-
     let response = http
         .client
         .request(
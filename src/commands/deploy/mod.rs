@@ -21,16 +21,15 @@ use crate::commands::ignite::types::{
     SingleDeployment,
 };
 use crate::commands::ignite::utils::{
-    create_deployment, env_file_to_map, rollout, update_deployment_config, WEB_IGNITE_URL,
+    create_deployment, env_file_to_map, rollout, update_deployment, update_deployment_config,
+    validate_autoscaling, WEB_IGNITE_URL,
 };
 use crate::commands::projects::utils::format_project;
-use crate::config::LEAP_PROJECT;
 use crate::state::State;
 use crate::store::hopfile::HopFile;
+use crate::utils::prompt;
 use crate::utils::urlify;
 
-const HOP_BUILD_BASE_URL: &str = "https://builder.hop.io/v1";
-
 #[derive(Debug, Parser)]
 #[clap(about = "Deploy a new container")]
 pub struct Options {
@@ -92,7 +91,7 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
             log::info!("Found hopfile: {}", hopfile.path.display());
 
             // TODO: possible update of deployment if it already exists?
-            let deployment = state
+            let mut deployment = state
                 .http
                 .request::<SingleDeployment>(
                     "GET",
@@ -121,12 +120,28 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
 
             log::info!("Deploying to project {}", format_project(&project));
 
-            // TODO: update when autoscaling is supported
-            let container_options = ContainerOptions {
-                containers: Some(deployment.container_count),
-                min_containers: None,
-                max_containers: None,
-            };
+            if let Some(autoscaling) = hopfile.config.autoscaling {
+                if deployment.config.autoscaling.as_ref() != Some(&autoscaling)
+                    && deployment.config.type_ != ContainerType::Stateful
+                {
+                    validate_autoscaling(&autoscaling)?;
+
+                    log::info!("Updating autoscaling of the deployment to {autoscaling}");
+
+                    deployment = update_deployment(
+                        &state.http,
+                        &deployment.id,
+                        &CreateDeployment {
+                            container_strategy: ScalingStrategy::Autoscaled,
+                            autoscaling: Some(autoscaling),
+                            ..CreateDeployment::from(deployment.clone())
+                        },
+                    )
+                    .await?;
+                }
+            }
+
+            let container_options = ContainerOptions::from_deployment(&deployment);
 
             (project, deployment, container_options, true)
         }
@@ -134,7 +149,7 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         None => {
             log::info!("No hopfile found, creating one");
 
-            let project = state.ctx.clone().current_project_error();
+            let project = state.ctx.clone().current_project_error()?;
 
             log::info!("Deploying to project {}", format_project(&project));
 
@@ -152,7 +167,6 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
                 (
                     CreateDeployment {
                         name: Some(default_name),
-                        // TODO: remove after autoscaling is supported
                         container_strategy: ScalingStrategy::Manual,
                         type_: Some(ContainerType::Persistent),
                         ..Default::default()
@@ -191,7 +205,7 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
             if options.envfile {
                 deployment_config
                     .env
-                    .extend(env_file_to_map(dir.join(".env")).await);
+                    .extend(env_file_to_map(dir.join(".env")).await?);
             }
 
             let deployment =
@@ -200,9 +214,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
             // skip gateway creation if using default config
             if !options.yes
                 && !is_visual
-                && dialoguer::Confirm::new()
-                    .with_prompt("Do you want to create a Gateway? (You can always add one later)")
-                    .interact()?
+                && prompt::is_interactive()
+                && prompt::confirm(
+                    "Do you want to create a Gateway? (You can always add one later)",
+                    false,
+                    "--yes",
+                )?
             {
                 let gateway_config = update_gateway_config(
                     &GatewayOptions::default(),
@@ -223,9 +240,10 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
                 }
             }
 
-            HopFile::new(dir.clone().join("hop.yml"), &project.id, &deployment.id)
-                .save()
-                .await?;
+            let mut hopfile =
+                HopFile::new(dir.clone().join("hop.yml"), &project.id, &deployment.id);
+            hopfile.config.autoscaling = deployment_config.autoscaling.clone();
+            hopfile.save().await?;
 
             (project, deployment, container_options, false)
         }
@@ -234,9 +252,8 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     // connect to leap here so no logs interfere with the deploy
     let mut leap = LeapEdge::new(LeapOptions {
         token: Some(&state.ctx.current.clone().unwrap().leap_token),
-        project: &std::env::var("LEAP_PROJECT").unwrap_or_else(|_| LEAP_PROJECT.to_string()),
-        ws_url: &std::env::var("LEAP_WS_URL")
-            .unwrap_or_else(|_| LeapOptions::default().ws_url.to_string()),
+        project: &state.ctx.leap_project(),
+        ws_url: &state.ctx.leap_ws_url(),
     })
     .await?;
 
@@ -1,13 +1,17 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::utils::resolve::Resource;
+
 #[derive(Serialize)]
 pub struct AttachDomain<'a> {
     pub domain: &'a str,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Domain {
     pub id: String,
     pub domain: String,
@@ -15,7 +19,19 @@ pub struct Domain {
     pub state: DomainState,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+impl Resource for Domain {
+    const KIND: &'static str = "domain";
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.domain)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum DomainState {
     Pending,
@@ -23,6 +39,14 @@ pub enum DomainState {
     ValidCname,
 }
 
+impl FromStr for DomainState {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        serde_json::from_str(&format!("\"{}\"", s.to_lowercase())).map_err(|e| anyhow!(e))
+    }
+}
+
 // this is only display for LIST
 impl Display for DomainState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
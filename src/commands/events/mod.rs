@@ -0,0 +1,148 @@
+mod utils;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use leap_client_rs::leap::types::Event;
+
+use self::utils::{format_event, EventType, ProjectEvent};
+use crate::commands::ignite::utils::{get_all_deployments, resolve_deployment};
+use crate::state::State;
+use crate::utils::leap;
+use crate::utils::output::StreamOutput;
+use crate::utils::prompt::Arg;
+
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Parser)]
+#[clap(about = "Stream the events of a project")]
+pub struct Options {
+    #[clap(
+        short,
+        long = "type",
+        help = "Only show events of these types, can be passed multiple times",
+        value_enum,
+        value_delimiter = ','
+    )]
+    pub types: Vec<EventType>,
+
+    #[clap(short, long, help = "Only show events of this deployment, ID or name")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the events",
+        value_enum,
+        default_value = "text"
+    )]
+    pub output: StreamOutput,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project = state.ctx.clone().current_project_error()?;
+
+    let deployment_id = match options.deployment {
+        Some(ref deployment) => Some(
+            resolve_deployment(
+                &state,
+                Some(deployment),
+                Arg::new("deployment", "--deployment"),
+            )
+            .await?
+            .id,
+        ),
+        None => None,
+    };
+
+    let names = get_all_deployments(&state.http, &project.id)
+        .await?
+        .into_iter()
+        .map(|d| (d.id, d.name))
+        .collect::<HashMap<_, _>>();
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    // how long to wait before connecting again, doubles on every failure
+    let mut delay = None;
+
+    loop {
+        if let Some(delay) = delay {
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = &mut ctrl_c => return Ok(()),
+            }
+        }
+
+        let mut leap = tokio::select! {
+            leap = leap::connect(&state, &project.id) => match leap {
+                Ok(leap) => leap,
+
+                Err(error) => {
+                    let next = next_delay(delay);
+                    log::warn!("Failed to connect to Leap, retrying in {}s: {error}", next.as_secs());
+                    delay = Some(next);
+                    continue;
+                }
+            },
+
+            _ = &mut ctrl_c => return Ok(()),
+        };
+
+        if options.output == StreamOutput::Text {
+            log::info!("Listening to the events of project `{}`", project.name);
+        }
+
+        loop {
+            let event = tokio::select! {
+                event = leap.listen() => event,
+
+                _ = &mut ctrl_c => {
+                    leap.close().await;
+
+                    return Ok(());
+                }
+            };
+
+            match event {
+                Some(Event::Message(capsuled))
+                    if capsuled.channel.as_ref() == Some(&project.id) =>
+                {
+                    // connected long enough to receive something
+                    delay = None;
+
+                    let event = ProjectEvent::new(capsuled.data.event, capsuled.data.data);
+
+                    if !options.types.is_empty() && !options.types.contains(&event.type_) {
+                        continue;
+                    }
+
+                    if deployment_id.is_some() && event.deployment_id != deployment_id {
+                        continue;
+                    }
+
+                    match options.output {
+                        StreamOutput::Text => println!("{}", format_event(&event, &names)),
+                        StreamOutput::Ndjson => println!("{}", serde_json::to_string(&event)?),
+                    }
+                }
+
+                Some(_) => {}
+
+                None => break,
+            }
+        }
+
+        delay = Some(next_delay(delay));
+        log::warn!("Lost the connection to Leap, reconnecting");
+    }
+}
+
+fn next_delay(delay: Option<Duration>) -> Duration {
+    delay.map_or(Duration::from_secs(1), |delay| {
+        (delay * 2).min(MAX_RECONNECT_DELAY)
+    })
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use chrono::{DateTime, Local};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum EventType {
+    Build,
+    Rollout,
+    Container,
+    Deployment,
+    Gateway,
+    /// events that fit none of the above
+    Other,
+}
+
+impl EventType {
+    /// Classifies an event by its name, e.g. `BUILD_PROGRESS` or `ROLLOUT_UPDATE`.
+    pub fn of(event: &str) -> Self {
+        match event.split('_').next().unwrap_or_default() {
+            "BUILD" | "PUSH" => Self::Build,
+            "ROLLOUT" => Self::Rollout,
+            "CONTAINER" => Self::Container,
+            "DEPLOYMENT" => Self::Deployment,
+            "GATEWAY" | "DOMAIN" => Self::Gateway,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl Display for EventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap().replace('"', "")
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectEvent {
+    pub received_at: DateTime<Local>,
+    #[serde(rename = "type")]
+    pub type_: EventType,
+    pub event: String,
+    pub deployment_id: Option<String>,
+    pub data: Value,
+}
+
+impl ProjectEvent {
+    pub fn new(event: String, data: Value) -> Self {
+        Self {
+            received_at: Local::now(),
+            type_: EventType::of(&event),
+            deployment_id: field(&data, "deployment_id")
+                .or_else(|| data.get("deployment")?.get("id")?.as_str())
+                .map(str::to_string),
+            event,
+            data,
+        }
+    }
+}
+
+/// A string field of the event, or of an object it wraps, like `{"build": {...}}`.
+fn field<'a>(data: &'a Value, key: &str) -> Option<&'a str> {
+    data.get(key).and_then(Value::as_str).or_else(|| {
+        data.as_object()?
+            .values()
+            .find_map(|value| value.get(key)?.as_str())
+    })
+}
+
+/// One line describing the event, `names` maps deployment IDs to names.
+pub fn format_event(event: &ProjectEvent, names: &HashMap<String, String>) -> String {
+    let deployment = event
+        .deployment_id
+        .as_ref()
+        .map_or("-", |id| names.get(id).unwrap_or(id));
+
+    let summary = match field(&event.data, "log") {
+        Some(log) => log.trim_end().to_string(),
+
+        None => {
+            let id = field(&event.data, "id").or_else(|| field(&event.data, "build_id"));
+
+            match (id, field(&event.data, "state")) {
+                (Some(id), Some(state)) => format!("{id} {state}"),
+                (Some(id), None) => id.to_string(),
+                (None, Some(state)) => state.to_string(),
+                (None, None) => event.data.to_string(),
+            }
+        }
+    };
+
+    format!(
+        "{}  {:<10}  {:<16}  {deployment}  {summary}",
+        event.received_at.format("%H:%M:%S"),
+        event.type_.to_string(),
+        event.event,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_project_event() {
+        let event = ProjectEvent::new(
+            "ROLLOUT_UPDATE".to_string(),
+            json!({ "rollout": { "id": "rollout_1", "deployment_id": "deployment_1", "state": "finished" } }),
+        );
+
+        assert_eq!(event.type_, EventType::Rollout);
+        assert_eq!(event.deployment_id.as_deref(), Some("deployment_1"));
+
+        let names = HashMap::from([("deployment_1".to_string(), "api".to_string())]);
+        assert!(format_event(&event, &names).ends_with("ROLLOUT_UPDATE    api  rollout_1 finished"));
+
+        let event = ProjectEvent::new(
+            "PUSH_SUCCESS".to_string(),
+            json!({ "build_id": "build_1", "deployment_id": "deployment_2" }),
+        );
+
+        assert_eq!(event.type_, EventType::Build);
+        assert!(format_event(&event, &names).ends_with("deployment_2  build_1"));
+
+        let event = ProjectEvent::new(
+            "SOMETHING_NEW".to_string(),
+            json!({ "deployment": { "id": "deployment_1" } }),
+        );
+        assert_eq!(event.type_, EventType::Other);
+        assert_eq!(event.deployment_id.as_deref(), Some("deployment_1"));
+    }
+}
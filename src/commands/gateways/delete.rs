@@ -1,9 +1,10 @@
-use anyhow::{bail, ensure, Result};
+use anyhow::{bail, Result};
 use clap::Parser;
 
 use crate::commands::gateways::util::{delete_gateway, format_gateways, get_all_gateways};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Delete gateways")]
@@ -19,28 +20,17 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     let gateways = if !options.gateways.is_empty() {
         options.gateways
     } else {
-        let project_id = state.ctx.current_project_error().id;
+        let deployment =
+            resolve_deployment(&state, None, Arg::new("gateways", "<GATEWAYS>")).await?;
 
-        let deployments = get_all_deployments(&state.http, &project_id).await?;
-        ensure!(!deployments.is_empty(), "No deployments found");
-        let deployments_fmt = format_deployments(&deployments, false);
-
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a deployment")
-            .items(&deployments_fmt)
-            .default(0)
-            .interact_opt()
-            .expect("Failed to select deployment")
-            .expect("No deployment selected");
-
-        let gateways = get_all_gateways(&state.http, &deployments[idx].id).await?;
+        let gateways = get_all_gateways(&state.http, &deployment.id).await?;
         let gateways_fmt = format_gateways(&gateways, false);
 
-        let idxs = dialoguer::MultiSelect::new()
-            .with_prompt("Select Gateways to delete")
-            .items(&gateways_fmt)
-            .interact_opt()?
-            .expect("No Gateway selected");
+        let idxs = prompt::multi_select(
+            "Select Gateways to delete",
+            &gateways_fmt,
+            Arg::new("gateways", "<GATEWAYS>"),
+        )?;
 
         gateways
             .iter()
@@ -51,13 +41,14 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     };
 
     if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt(format!(
+        && !prompt::confirm(
+            format!(
                 "Are you sure you want to delete {} Gateways?",
                 gateways.len()
-            ))
-            .interact_opt()?
-            .unwrap_or(false)
+            ),
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted");
     }
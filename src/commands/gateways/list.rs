@@ -1,14 +1,15 @@
-use anyhow::{ensure, Result};
+use anyhow::Result;
 use clap::Parser;
 
 use crate::commands::gateways::util::{format_gateways, get_all_gateways};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "List all Gateways")]
 pub struct Options {
-    #[clap(name = "deployment", help = "ID of the deployment")]
+    #[clap(name = "deployment", help = "ID or name of the deployment")]
     pub deployment: Option<String>,
 
     #[clap(
@@ -20,27 +21,13 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment_id = match options.deployment {
-        Some(deployment) => deployment,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
-
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()
-                .expect("Failed to select deployment")
-                .expect("No deployment selected");
-
-            deployments[idx].id.clone()
-        }
-    };
+    let deployment_id = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?
+    .id;
 
     let gateways = get_all_gateways(&state.http, &deployment_id).await?;
 
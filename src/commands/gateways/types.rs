@@ -5,6 +5,7 @@ use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
 
 use crate::commands::domains::types::Domain;
+use crate::utils::resolve::Resource;
 
 #[derive(Debug, Serialize, Clone, Default, PartialEq, Eq)]
 pub struct GatewayConfig {
@@ -32,7 +33,7 @@ impl GatewayConfig {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Gateway {
     pub id: String,
     pub created_at: String,
@@ -47,6 +48,18 @@ pub struct Gateway {
     pub domains: Vec<Domain>,
 }
 
+impl Resource for Gateway {
+    const KIND: &'static str = "gateway";
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
 impl Gateway {
     pub fn full_url(&self) -> String {
         match self.type_ {
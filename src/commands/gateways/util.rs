@@ -1,6 +1,6 @@
 use std::io::Write;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
 use regex::Regex;
 use serde_json::Value;
 
@@ -8,8 +8,12 @@ use super::create::GatewayOptions;
 use super::types::{
     Gateway, GatewayConfig, GatewayProtocol, GatewayType, MultipleGateways, SingleGateway,
 };
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::http::HttpClient;
+use crate::state::State;
 use crate::utils::ask_question_iter;
+use crate::utils::prompt::{self, Arg};
+use crate::utils::resolve;
 
 pub async fn create_gateway(
     http: &HttpClient,
@@ -35,9 +39,7 @@ pub async fn get_all_gateways(http: &HttpClient, deployment_id: &str) -> Result<
     let response = http
         .request::<MultipleGateways>(
             "GET",
-            &format!(
-                "/ignite/deployments/{deployment_id}/gateways"
-            ),
+            &format!("/ignite/deployments/{deployment_id}/gateways"),
             None,
         )
         .await?
@@ -55,6 +57,39 @@ pub async fn get_gateway(http: &HttpClient, gateway_id: &str) -> Result<Gateway>
     Ok(response.gateway)
 }
 
+/// Resolves a Gateway from an ID, or from a name or a picker after
+/// resolving the deployment it belongs to.
+pub async fn resolve_gateway(
+    state: &State,
+    query: Option<&str>,
+    deployment: Option<&str>,
+    arg: Arg<'_>,
+) -> Result<Gateway> {
+    if let Some(query) = query {
+        if resolve::is_id::<Gateway>(query) {
+            return get_gateway(&state.http, query).await;
+        }
+    }
+
+    let deployment =
+        resolve_deployment(state, deployment, Arg::new("deployment", "--deployment")).await?;
+
+    let gateways = get_all_gateways(&state.http, &deployment.id).await?;
+    ensure!(!gateways.is_empty(), "No Gateways found");
+
+    match query {
+        Some(query) => resolve::find(&gateways, query).cloned(),
+
+        None => {
+            let gateways_fmt = format_gateways(&gateways, false);
+
+            let idx = prompt::fuzzy_select("Select a Gateway", &gateways_fmt, arg)?;
+
+            Ok(gateways[idx].clone())
+        }
+    }
+}
+
 pub async fn update_gateway(
     http: &HttpClient,
     gateway_id: &str,
@@ -174,6 +209,11 @@ fn update_config_from_args(
 }
 
 fn update_config_from_guided(gateway_config: &mut GatewayConfig, is_update: bool) -> Result<()> {
+    prompt::ensure_interactive(Arg::new(
+        "gateway config",
+        "the config as flags, see `--help`",
+    ))?;
+
     let name = gateway_config.name.clone().unwrap_or_default();
 
     gateway_config.name = Some(
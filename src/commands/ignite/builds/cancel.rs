@@ -3,13 +3,14 @@ use clap::Parser;
 
 use super::utils::{cancel_build, format_builds, get_all_builds};
 use crate::commands::ignite::builds::types::BuildState;
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Cancel a running build")]
 pub struct Options {
-    #[clap(help = "ID of the deployment")]
+    #[clap(help = "ID of the build")]
     pub build: Option<String>,
 
     #[clap(short, long, help = "Skip confirmation")]
@@ -21,20 +22,9 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         Some(id) => id,
 
         None => {
-            let project_id = state.ctx.current_project_error().id;
+            let deployment = resolve_deployment(&state, None, Arg::new("build", "<BUILD>")).await?;
 
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()?
-                .ok_or_else(|| anyhow::anyhow!("No deployment selected"))?;
-
-            let builds = get_all_builds(&state.http, &deployments[idx].id)
+            let builds = get_all_builds(&state.http, &deployment.id)
                 .await?
                 .into_iter()
                 .filter(|b| matches!(b.state, BuildState::Pending))
@@ -42,21 +32,23 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
             ensure!(!builds.is_empty(), "No running builds found");
             let builds_fmt = format_builds(&builds, false);
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a build")
-                .items(&builds_fmt)
-                .default(0)
-                .interact_opt()?
-                .ok_or_else(|| anyhow::anyhow!("No build selected"))?;
+            let idx = prompt::select(
+                "Select a build",
+                &builds_fmt,
+                0,
+                Arg::new("build", "<BUILD>"),
+            )?;
 
             builds[idx].id.clone()
         }
     };
 
     if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt("Are you sure you want to cancel this build?")
-            .interact()?
+        && !prompt::confirm(
+            "Are you sure you want to cancel this build?",
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted by user");
     }
@@ -8,6 +8,11 @@ pub struct MultipleBuilds {
     pub builds: Vec<Build>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SingleBuild {
+    pub build: Build,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum BuildMethod {
@@ -44,7 +49,7 @@ impl Display for BuildState {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Build {
     pub id: String,
     pub deployment_id: String,
@@ -4,7 +4,7 @@ use anyhow::Result;
 use ms::{__to_string__, ms};
 use serde_json::Value;
 
-use super::types::{Build, MultipleBuilds};
+use super::types::{Build, MultipleBuilds, SingleBuild};
 use crate::state::http::HttpClient;
 use crate::utils::relative_time;
 
@@ -25,6 +25,15 @@ pub async fn get_all_builds(http: &HttpClient, deployment_id: &str) -> Result<Ve
     Ok(response.builds)
 }
 
+pub async fn get_build(http: &HttpClient, build_id: &str) -> Result<Build> {
+    let response = http
+        .request::<SingleBuild>("GET", &format!("/ignite/builds/{build_id}"), None)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Could not parse response"))?;
+
+    Ok(response.build)
+}
+
 pub async fn cancel_build(http: &HttpClient, build_id: &str) -> Result<()> {
     http.request::<Value>("POST", &format!("/ignite/builds/{build_id}/cancel"), None)
         .await?;
@@ -0,0 +1,374 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use clap::Parser;
+use serde::Serialize;
+
+use super::health::utils::get_all_health_checks;
+use super::types::Deployment;
+use super::utils::{get_all_deployments, get_deployment, resolve_deployment};
+use crate::commands::gateways::types::Gateway;
+use crate::commands::gateways::util::get_all_gateways;
+use crate::commands::secrets::utils::get_secret_name;
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::diff::{format_diff, DiffLine};
+use crate::utils::output::{print_json, OutputFormat};
+use crate::utils::prompt::Arg;
+use crate::utils::resolve;
+
+const REDACTED: &str = "<redacted>";
+
+#[derive(Debug, Parser)]
+#[clap(about = "Show how the config of two deployments differs")]
+pub struct Options {
+    #[clap(help = "ID or name of the first deployment")]
+    pub a: String,
+
+    #[clap(help = "ID or name of the second deployment")]
+    pub b: String,
+
+    #[clap(long, help = "Namespace or ID of the project of the first deployment")]
+    pub project_a: Option<String>,
+
+    #[clap(long, help = "Namespace or ID of the project of the second deployment")]
+    pub project_b: Option<String>,
+
+    #[clap(long, help = "Show the values of environment variables")]
+    pub show_values: bool,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the differences",
+        value_enum,
+        default_value = "text"
+    )]
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct Difference {
+    field: String,
+    a: Option<String>,
+    b: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Comparison {
+    a: String,
+    b: String,
+    differences: Vec<Difference>,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let (a, b) = tokio::try_join!(
+        fields_of(&state, &options.a, options.project_a.as_deref()),
+        fields_of(&state, &options.b, options.project_b.as_deref()),
+    )?;
+
+    let comparison = Comparison {
+        differences: compare(&a.1, &b.1, options.show_values),
+        a: a.0,
+        b: b.0,
+    };
+
+    if options.output == OutputFormat::Json {
+        return print_json(&comparison);
+    }
+
+    if comparison.differences.is_empty() {
+        log::info!(
+            "`{}` and `{}` have the same config",
+            comparison.a,
+            comparison.b
+        );
+
+        return Ok(());
+    }
+
+    log::info!("Comparing `{}` (-) to `{}` (+)", comparison.a, comparison.b);
+
+    let lines = comparison
+        .differences
+        .iter()
+        .map(|d| {
+            (
+                d.a.as_ref().map(|a| format!("{}: {a}", d.field)),
+                d.b.as_ref().map(|b| format!("{}: {b}", d.field)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let diff = lines
+        .iter()
+        .flat_map(|(a, b)| {
+            [
+                a.as_deref().map(DiffLine::Removed),
+                b.as_deref().map(DiffLine::Added),
+            ]
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    println!("{}", format_diff(&diff, true).join("\n"));
+
+    Ok(())
+}
+
+/// The differing fields, env values are redacted unless `show_values`.
+fn compare(
+    a: &BTreeMap<String, String>,
+    b: &BTreeMap<String, String>,
+    show_values: bool,
+) -> Vec<Difference> {
+    a.keys()
+        .chain(b.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|field| a.get(*field) != b.get(*field))
+        .map(|field| {
+            let redact = |value: &String| {
+                // secret references are not secret themselves
+                if !show_values && field.starts_with("env.") && get_secret_name(value).is_none() {
+                    REDACTED.to_string()
+                } else {
+                    value.clone()
+                }
+            };
+
+            Difference {
+                field: field.clone(),
+                a: a.get(field).map(redact),
+                b: b.get(field).map(redact),
+            }
+        })
+        .collect()
+}
+
+/// Finds a deployment, in another project when `project` is passed.
+async fn find_deployment(state: &State, query: &str, project: Option<&str>) -> Result<Deployment> {
+    let Some(project) = project else {
+        return resolve_deployment(state, Some(query), Arg::new("deployment", "<DEPLOYMENT>"))
+            .await;
+    };
+
+    if resolve::is_id::<Deployment>(query) {
+        return get_deployment(&state.http, query).await;
+    }
+
+    let project = state
+        .ctx
+        .find_project_by_id_or_namespace(project)
+        .ok_or_else(|| {
+            HopError::NotFound(format!("No project with the ID or namespace `{project}`"))
+        })?;
+
+    let deployments = get_all_deployments(&state.http, &project.id).await?;
+
+    resolve::find(&deployments, query).cloned()
+}
+
+/// The name of a deployment and its config flattened into fields like
+/// `resources.ram` or `env.PORT`, with its gateways and health checks.
+async fn fields_of(
+    state: &State,
+    query: &str,
+    project: Option<&str>,
+) -> Result<(String, BTreeMap<String, String>)> {
+    let deployment = find_deployment(state, query, project).await?;
+
+    let (gateways, health_checks) = tokio::try_join!(
+        get_all_gateways(&state.http, &deployment.id),
+        get_all_health_checks(&state.http, &deployment.id),
+    )?;
+
+    let config = &deployment.config;
+    let mut fields = BTreeMap::new();
+
+    let mut set = |field: String, value: String| {
+        fields.insert(field, value);
+    };
+
+    set("image".into(), config.image.name.clone());
+    set("type".into(), config.type_.to_string());
+    set("strategy".into(), config.container_strategy.to_string());
+    set("resources.vcpu".into(), config.resources.vcpu.to_string());
+    set("resources.ram".into(), config.resources.ram.clone());
+
+    if let Some(ref autoscaling) = config.autoscaling {
+        set("autoscaling".into(), autoscaling.to_string());
+    }
+
+    if let Some(ref restart_policy) = config.restart_policy {
+        set("restart_policy".into(), restart_policy.to_string());
+    }
+
+    if let Some(ref volume) = config.volume {
+        set("volume.size".into(), volume.size.clone());
+        set("volume.mountpath".into(), volume.mount_path.clone());
+        set("volume.fs".into(), volume.fs.to_string());
+    }
+
+    if let Some(ref entrypoint) = config.entrypoint {
+        set("entrypoint".into(), entrypoint.join(" "));
+    }
+
+    if let Some(ref cmd) = config.cmd {
+        set("cmd".into(), cmd.join(" "));
+    }
+
+    for (key, value) in &config.env {
+        set(format!("env.{key}"), value.clone());
+    }
+
+    for (name, gateway) in gateway_keys(&gateways) {
+        set(format!("gateways.{name}.type"), gateway.type_.to_string());
+
+        if let Some(ref protocol) = gateway.protocol {
+            set(format!("gateways.{name}.protocol"), protocol.to_string());
+        }
+
+        if let Some(port) = gateway.target_port {
+            set(format!("gateways.{name}.target_port"), port.to_string());
+        }
+    }
+
+    for check in &health_checks {
+        let prefix = format!("health_checks.{}", check.type_);
+
+        set(
+            format!("{prefix}.target"),
+            format!("{} :{}{}", check.protocol, check.port, check.path),
+        );
+        set(
+            format!("{prefix}.timing"),
+            format!(
+                "initial delay {}s, interval {}s, timeout {}ms",
+                check.initial_delay, check.interval, check.timeout
+            ),
+        );
+        set(
+            format!("{prefix}.thresholds"),
+            format!(
+                "{} retries, {} successes",
+                check.max_retries, check.success_threshold
+            ),
+        );
+    }
+
+    Ok((deployment.name, fields))
+}
+
+/// Keys Gateways by name, or by type, protocol and port when unnamed so
+/// the same Gateway of two deployments is compared. Gateways that would
+/// still share a key get a `#n` suffix, in a stable order.
+fn gateway_keys(gateways: &[Gateway]) -> Vec<(String, &Gateway)> {
+    let mut gateways = gateways.iter().collect::<Vec<_>>();
+    gateways.sort_by_key(|g| {
+        (
+            g.name.clone(),
+            g.type_.to_string(),
+            g.protocol.as_ref().map(ToString::to_string),
+            g.target_port,
+            g.id.clone(),
+        )
+    });
+
+    let mut keys = BTreeMap::<String, usize>::new();
+
+    gateways
+        .into_iter()
+        .map(|gateway| {
+            let key = gateway.name.clone().unwrap_or_else(|| {
+                let protocol = gateway
+                    .protocol
+                    .as_ref()
+                    .map_or_else(|| "-".to_string(), ToString::to_string);
+                let port = gateway
+                    .target_port
+                    .map_or_else(|| "-".to_string(), |port| port.to_string());
+
+                format!("{}:{protocol}:{port}", gateway.type_)
+            });
+
+            let count = keys.entry(key.clone()).or_default();
+            *count += 1;
+
+            let key = if *count > 1 {
+                format!("{key}#{count}")
+            } else {
+                key
+            };
+
+            (key, gateway)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let a = BTreeMap::from([
+            ("image".to_string(), "api:1".to_string()),
+            ("env.PORT".to_string(), "8080".to_string()),
+            ("env.TOKEN".to_string(), "a".to_string()),
+            ("env.DB".to_string(), "${secrets.DB}".to_string()),
+        ]);
+        let b = BTreeMap::from([
+            ("image".to_string(), "api:2".to_string()),
+            ("env.TOKEN".to_string(), "a".to_string()),
+            ("cmd".to_string(), "serve".to_string()),
+        ]);
+
+        let differences = compare(&a, &b, false);
+        let fields = differences
+            .iter()
+            .map(|d| d.field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["cmd", "env.DB", "env.PORT", "image"]);
+
+        assert_eq!(differences[0].a, None);
+        assert_eq!(differences[1].a.as_deref(), Some("${secrets.DB}"));
+        assert_eq!(differences[2].a.as_deref(), Some(REDACTED));
+        assert_eq!(differences[2].b, None);
+        assert_eq!(differences[3].b.as_deref(), Some("api:2"));
+
+        assert_eq!(compare(&a, &b, true)[2].a.as_deref(), Some("8080"));
+    }
+
+    #[test]
+    fn test_gateway_keys() {
+        let gateway = |id: &str, name: Option<&str>, port: u16| Gateway {
+            id: id.to_string(),
+            name: name.map(str::to_string),
+            target_port: Some(port),
+            ..Default::default()
+        };
+
+        let gateways = [
+            gateway("gateway_3", None, 8080),
+            gateway("gateway_1", Some("web"), 80),
+            gateway("gateway_2", None, 3000),
+            gateway("gateway_4", None, 8080),
+        ];
+
+        let keys = gateway_keys(&gateways)
+            .into_iter()
+            .map(|(key, gateway)| (key, gateway.id.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            keys,
+            [
+                ("external:-:3000".to_string(), "gateway_2"),
+                ("external:-:8080".to_string(), "gateway_3"),
+                ("external:-:8080#2".to_string(), "gateway_4"),
+                ("web".to_string(), "gateway_1"),
+            ]
+        );
+    }
+}
@@ -16,10 +16,13 @@ pub struct DeploymentConfig {
     #[clap(
         short = 's',
         long = "strategy",
-        help = "Scaling strategy, defaults to `autoscale`"
+        help = "Scaling strategy, `manual` or `autoscale`, defaults to `manual`"
     )]
     pub scaling_strategy: Option<ScalingStrategy>,
 
+    #[clap(flatten)]
+    pub autoscaling: AutoscalingConfig,
+
     #[clap(short, long, help = "Tier of the deployment")]
     pub tier: Option<String>,
 
@@ -63,6 +66,27 @@ pub struct DeploymentConfig {
     pub rm: bool,
 }
 
+#[derive(Debug, Parser, Default, PartialEq, Eq, Clone)]
+pub struct AutoscalingConfig {
+    #[clap(long, help = "Minimum amount of containers if `strategy` is autoscale")]
+    pub min_containers: Option<u64>,
+
+    #[clap(long, help = "Maximum amount of containers if `strategy` is autoscale")]
+    pub max_containers: Option<u64>,
+
+    #[clap(
+        long,
+        help = "CPU usage percentage to scale at if `strategy` is autoscale"
+    )]
+    pub target_cpu: Option<u8>,
+
+    #[clap(
+        long,
+        help = "Memory usage percentage to scale at if `strategy` is autoscale"
+    )]
+    pub target_memory: Option<u8>,
+}
+
 #[derive(Debug, Parser, Default, PartialEq, Eq, Clone)]
 pub struct VolumeConfig {
     #[clap(short, long, help = "Volume mount to use")]
@@ -86,7 +110,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project = state.ctx.current_project_error();
+    let project = state.ctx.current_project_error()?;
 
     log::info!(
         "Deploying to project {} /{} ({})",
@@ -0,0 +1,275 @@
+use std::io::Write;
+
+use anyhow::Result;
+use clap::Parser;
+use serde::Serialize;
+use tabwriter::TabWriter;
+
+use super::builds::types::Build;
+use super::builds::utils::{format_builds, get_all_builds};
+use super::health::types::{HealthCheck, HealthCheckState};
+use super::health::utils::{get_all_health_checks, get_health_state};
+use super::types::Deployment;
+use super::utils::{resolve_deployment, WEB_IGNITE_URL};
+use crate::commands::containers::types::Container;
+use crate::commands::containers::utils::{format_containers, get_all_containers};
+use crate::commands::gateways::types::Gateway;
+use crate::commands::gateways::util::get_all_gateways;
+use crate::commands::secrets::utils::get_secret_name;
+use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
+use crate::utils::prompt::Arg;
+
+const BUILDS_SHOWN: usize = 5;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Show everything about a deployment at once")]
+pub struct Options {
+    #[clap(help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the deployment",
+        value_enum,
+        default_value = "text"
+    )]
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct EnvKey {
+    key: String,
+    /// name of the secret the value comes from
+    secret: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct HealthCheckDescription {
+    #[serde(flatten)]
+    health_check: HealthCheck,
+    states: Vec<HealthCheckState>,
+}
+
+#[derive(Debug, Serialize)]
+struct Description {
+    deployment: Deployment,
+    env: Vec<EnvKey>,
+    containers: Vec<Container>,
+    gateways: Vec<Gateway>,
+    health_checks: Vec<HealthCheckDescription>,
+    builds: Vec<Build>,
+    url: String,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let mut deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
+
+    let (containers, gateways, health_checks, mut health_states, mut builds) = tokio::try_join!(
+        get_all_containers(&state.http, &deployment.id),
+        get_all_gateways(&state.http, &deployment.id),
+        get_all_health_checks(&state.http, &deployment.id),
+        get_health_state(&state.http, &deployment.id),
+        get_all_builds(&state.http, &deployment.id),
+    )?;
+
+    builds.truncate(BUILDS_SHOWN);
+
+    let mut env = deployment
+        .config
+        .env
+        .drain()
+        .map(|(key, value)| EnvKey {
+            key,
+            secret: get_secret_name(&value),
+        })
+        .collect::<Vec<_>>();
+    env.sort_by(|a, b| a.key.cmp(&b.key));
+
+    let health_checks = health_checks
+        .into_iter()
+        .map(|health_check| {
+            let (states, rest) = health_states
+                .drain(..)
+                .partition(|s| s.health_check_id == health_check.id);
+            health_states = rest;
+
+            HealthCheckDescription {
+                health_check,
+                states,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let url = match state.ctx.current_project() {
+        Some(project) => format!(
+            "{WEB_IGNITE_URL}/deployment/{}?project={}",
+            deployment.id, project.namespace
+        ),
+        None => format!("{WEB_IGNITE_URL}/deployment/{}", deployment.id),
+    };
+
+    let description = Description {
+        deployment,
+        env,
+        containers,
+        gateways,
+        health_checks,
+        builds,
+        url,
+    };
+
+    if options.output == OutputFormat::Json {
+        return print_json(&description);
+    }
+
+    log::info!("Deployment: `{}`", description.deployment.name);
+    print!("{}", format_description(&description)?);
+
+    Ok(())
+}
+
+fn format_description(description: &Description) -> Result<String> {
+    let deployment = &description.deployment;
+    let config = &deployment.config;
+
+    let mut tw = TabWriter::new(vec![]);
+
+    writeln!(tw, "Name\t{}", deployment.name)?;
+    writeln!(tw, "ID\t{}", deployment.id)?;
+    writeln!(tw, "Created\t{}", deployment.created_at)?;
+    writeln!(tw, "Type\t{}", config.type_)?;
+    writeln!(tw, "Image\t{}", config.image.name)?;
+
+    match config.autoscaling {
+        Some(ref autoscaling) if deployment.is_autoscaled() => writeln!(
+            tw,
+            "Containers\t{} (autoscaled {autoscaling})",
+            deployment.container_count
+        )?,
+        _ => writeln!(
+            tw,
+            "Containers\t{}/{}",
+            deployment.container_count, deployment.target_container_count
+        )?,
+    }
+
+    writeln!(
+        tw,
+        "Resources\t{} vCPU, {} RAM",
+        config.resources.vcpu, config.resources.ram
+    )?;
+    writeln!(
+        tw,
+        "Restart policy\t{}",
+        config
+            .restart_policy
+            .as_ref()
+            .map_or_else(|| "-".to_string(), ToString::to_string)
+    )?;
+    writeln!(
+        tw,
+        "Volume\t{}",
+        config.volume.as_ref().map_or_else(
+            || "-".to_string(),
+            |v| format!("{} at {} ({})", v.size, v.mount_path, v.fs)
+        )
+    )?;
+    writeln!(tw, "Console\t{}", description.url)?;
+
+    section(&mut tw, "Environment", description.env.is_empty())?;
+
+    for env in &description.env {
+        match env.secret {
+            Some(ref secret) => writeln!(tw, "{}\tsecret `{secret}`", env.key)?,
+            None => writeln!(tw, "{}", env.key)?,
+        }
+    }
+
+    section(&mut tw, "Containers", description.containers.is_empty())?;
+
+    if !description.containers.is_empty() {
+        // already aligned, lines without tabs are passed through
+        for line in format_containers(&description.containers, true) {
+            writeln!(tw, "{line}")?;
+        }
+    }
+
+    section(&mut tw, "Gateways", description.gateways.is_empty())?;
+
+    for gateway in &description.gateways {
+        let domains = gateway
+            .domains
+            .iter()
+            .map(|d| format!("{} ({})", d.domain, d.state))
+            .collect::<Vec<_>>();
+
+        writeln!(
+            tw,
+            "{}\t{}\t{}\t{}",
+            gateway.name.as_deref().unwrap_or(&gateway.id),
+            gateway.type_,
+            gateway.full_url(),
+            if domains.is_empty() {
+                "-".to_string()
+            } else {
+                domains.join(", ")
+            }
+        )?;
+    }
+
+    section(
+        &mut tw,
+        "Health Checks",
+        description.health_checks.is_empty(),
+    )?;
+
+    for check in &description.health_checks {
+        let states = check
+            .states
+            .iter()
+            .map(|s| format!("{}: {}", s.container_id, s.state))
+            .collect::<Vec<_>>();
+
+        writeln!(
+            tw,
+            "{}\t{}\t{}\t{}\t{}",
+            check.health_check.id,
+            check.health_check.protocol,
+            check.health_check.port,
+            check.health_check.path,
+            if states.is_empty() {
+                "-".to_string()
+            } else {
+                states.join(", ")
+            }
+        )?;
+    }
+
+    section(&mut tw, "Latest Builds", description.builds.is_empty())?;
+
+    if !description.builds.is_empty() {
+        for line in format_builds(&description.builds, true) {
+            writeln!(tw, "{line}")?;
+        }
+    }
+
+    Ok(String::from_utf8(tw.into_inner()?)?)
+}
+
+fn section(tw: &mut TabWriter<Vec<u8>>, title: &str, empty: bool) -> Result<()> {
+    writeln!(tw)?;
+    writeln!(tw, "{title}")?;
+
+    if empty {
+        writeln!(tw, "-")?;
+    }
+
+    Ok(())
+}
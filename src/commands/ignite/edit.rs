@@ -0,0 +1,167 @@
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Parser;
+
+use super::types::{CreateDeployment, ScalingStrategy};
+use crate::commands::ignite::utils::{
+    resolve_deployment, rollout, update_deployment, validate_autoscaling, validate_cpu_count,
+    validate_deployment_name,
+};
+use crate::config::DEFAULT_EDITOR;
+use crate::config::EXEC_NAME;
+use crate::state::State;
+use crate::utils::diff::{diff_lines, format_diff, has_changes};
+use crate::utils::prompt::{self, Arg};
+use crate::utils::size::parse_size;
+
+const HEADER: &str = "# Edit the deployment below, lines beginning with '#' are ignored.
+# Saving an empty file aborts the edit.
+#
+";
+
+#[derive(Debug, Parser)]
+#[clap(about = "Edit a deployment's config in your editor")]
+pub struct Options {
+    #[clap(help = "ID or name of the deployment to edit")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Apply the changes without asking for confirmation"
+    )]
+    pub yes: bool,
+
+    #[clap(long, help = "Do not roll out the changes")]
+    pub no_rollout: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
+
+    let original = to_yaml(&CreateDeployment::from(deployment.clone()))?;
+
+    let editor = std::env::var("EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
+
+    let mut content = format!("{HEADER}{original}");
+
+    let (config, edited) = loop {
+        let Some(edited) = prompt::editor(&format!("use `{EXEC_NAME} ignite update` instead"))?
+            .executable(&editor)
+            .extension(".yml")
+            .require_save(true)
+            .edit(&content)?
+        else {
+            log::info!("Edit cancelled, no changes were made");
+
+            return Ok(());
+        };
+
+        let edited = strip_comments(&edited);
+
+        if edited.trim().is_empty() {
+            log::info!("Empty file, no changes were made");
+
+            return Ok(());
+        }
+
+        match parse_config(&edited) {
+            Ok(config) => {
+                let edited = to_yaml(&config)?;
+
+                break (config, edited);
+            }
+
+            Err(why) => {
+                log::error!("Invalid deployment config: {why}");
+
+                content = format!("# Error: {why}\n{HEADER}{edited}");
+            }
+        }
+    };
+
+    let diff = diff_lines(&original, &edited);
+
+    if !has_changes(&diff) {
+        log::info!("No changes were made");
+
+        return Ok(());
+    }
+
+    println!("{}", format_diff(&diff, true).join("\n"));
+
+    if !options.yes && !prompt::confirm("Apply these changes?", true, "--yes")? {
+        bail!("Aborted, no changes were made");
+    }
+
+    let updated = update_deployment(&state.http, &deployment.id, &config)
+        .await
+        .map_err(|e| anyhow!("Failed to update deployment: {e}"))?;
+
+    if updated.can_rollout() && !options.no_rollout {
+        log::info!("Rolling out new containers");
+        rollout(&state.http, &updated.id).await?;
+    }
+
+    log::info!("Deployment `{}` ({}) updated", updated.name, updated.id);
+
+    Ok(())
+}
+
+/// Serializes through `serde_json::Value` so keys, including env vars,
+/// always come out in the same order.
+fn to_yaml(config: &CreateDeployment) -> Result<String> {
+    Ok(serde_yaml::to_string(&serde_json::to_value(config)?)?)
+}
+
+fn strip_comments(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+fn parse_config(content: &str) -> Result<CreateDeployment> {
+    let config = serde_yaml::from_str::<CreateDeployment>(content)?;
+
+    let name = config
+        .name
+        .as_ref()
+        .ok_or_else(|| anyhow!("`name` is required"))?;
+
+    validate_deployment_name(name)?;
+
+    ensure!(
+        name == &name.to_lowercase(),
+        "Deployment name must be lowercase"
+    );
+
+    ensure!(
+        config.image.as_ref().is_some_and(|i| !i.name.is_empty()),
+        "`image.name` is required"
+    );
+
+    if let Err(why) = validate_cpu_count(&config.resources.vcpu) {
+        bail!("{why}")
+    }
+
+    parse_size(&config.resources.ram)?;
+
+    if config.container_strategy == ScalingStrategy::Autoscaled {
+        let autoscaling = config
+            .autoscaling
+            .as_ref()
+            .ok_or_else(|| anyhow!("`autoscaling` is required when the strategy is autoscale"))?;
+
+        validate_autoscaling(autoscaling)?;
+    }
+
+    Ok(config)
+}
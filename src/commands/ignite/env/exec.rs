@@ -0,0 +1,273 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use tokio::process::Command;
+
+use super::utils::format_env;
+use crate::commands::ignite::utils::{env_file_to_map, resolve_deployment};
+use crate::commands::secrets::utils::get_secret_name;
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::prompt::{self, Arg};
+
+/// Replaced by the path of the .env file in the command
+const DOTENV_PLACEHOLDER: &str = "{dotenv}";
+/// Set to the path of the .env file for the command
+const DOTENV_PATH_ENV: &str = "HOP_DOTENV";
+
+#[derive(Debug, Parser)]
+#[clap(
+    about = "Run a local command with the environment variables of a deployment",
+    trailing_var_arg = true
+)]
+pub struct Options {
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "File with the values of secrets, as `NAME=value` lines. Missing secrets are prompted for"
+    )]
+    pub secrets: Option<PathBuf>,
+
+    #[clap(
+        long,
+        help = "Write the variables to a temporary .env file instead, its path replaces `{dotenv}` in the command and is set as `HOP_DOTENV`"
+    )]
+    pub dotenv: bool,
+
+    #[clap(help = "The command to run, after `--`", last = true, required = true)]
+    pub command: Vec<String>,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    let mut secrets = match options.secrets {
+        // secret names are uppercase, like the ones in `${secrets.NAME}` references
+        Some(path) => env_file_to_map(path)
+            .await?
+            .into_iter()
+            .map(|(name, value)| (name.to_uppercase(), value))
+            .collect(),
+        None => HashMap::new(),
+    };
+
+    if prompt::is_interactive() {
+        let (_, unresolved) = resolve_env(&deployment.config.env, &secrets);
+
+        for name in unresolved.into_values().collect::<BTreeSet<_>>() {
+            let value = prompt::password(
+                format!("Value of secret `{name}`, empty to leave it out"),
+                Arg::new("secrets", "--secrets"),
+            )?
+            .allow_empty_password(true)
+            .interact()?;
+
+            if !value.is_empty() {
+                secrets.insert(name, value);
+            }
+        }
+    }
+
+    let (env, unresolved) = resolve_env(&deployment.config.env, &secrets);
+
+    if !unresolved.is_empty() {
+        let mut unresolved = unresolved
+            .into_iter()
+            .map(|(key, secret)| format!("{key} (secret `{secret}`)"))
+            .collect::<Vec<_>>();
+        unresolved.sort();
+
+        log::warn!(
+            "Secrets left unresolved, these variables are not set: {}",
+            unresolved.join(", ")
+        );
+    }
+
+    // removed when dropped, so on every way out of this function
+    let dotenv = if options.dotenv {
+        Some(TempDotenv::write(&format_env(&env))?)
+    } else {
+        None
+    };
+
+    let args = options.command[1..].iter().map(|arg| match dotenv {
+        Some(ref dotenv) if arg == DOTENV_PLACEHOLDER => dotenv.path.display().to_string(),
+        _ => arg.clone(),
+    });
+
+    let mut command = Command::new(&options.command[0]);
+    command.args(args);
+
+    if let Some(ref dotenv) = dotenv {
+        command.env(DOTENV_PATH_ENV, &dotenv.path);
+
+        log::info!(
+            "Wrote {} variables of `{}` to {}, it is removed when the command exits",
+            env.len(),
+            deployment.name,
+            dotenv.path.display()
+        );
+    } else {
+        command.envs(&env);
+
+        log::info!(
+            "Running with {} variables of `{}`",
+            env.len(),
+            deployment.name
+        );
+    }
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to run `{}`", options.command[0]))?;
+
+    // ctrl-c reaches the command too, wait for it to exit instead of
+    // leaving the .env file behind
+    let status = loop {
+        tokio::select! {
+            status = child.wait() => break status?,
+            _ = tokio::signal::ctrl_c() => {}
+        }
+    };
+
+    if !status.success() {
+        return Err(HopError::Exited(
+            match status.code() {
+                Some(code) => format!("`{}` exited with code {code}", options.command[0]),
+                None => format!("`{}` was stopped by a signal", options.command[0]),
+            },
+            exit_code(status),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// The exit code of the command, `128 + signal` when a signal stopped it like shells do.
+fn exit_code(status: ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+
+    HopError::DEFAULT_EXIT_CODE
+}
+
+/// A .env file only the current user can read, removed when dropped.
+struct TempDotenv {
+    path: PathBuf,
+}
+
+impl TempDotenv {
+    fn write(lines: &[String]) -> Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "hop-env-{}-{}.env",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos()
+        ));
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+
+            options.mode(0o600);
+        }
+
+        let dotenv = Self { path };
+
+        let mut file = options
+            .open(&dotenv.path)
+            .with_context(|| format!("Failed to create {}", dotenv.path.display()))?;
+        file.write_all((lines.join("\n") + "\n").as_bytes())?;
+
+        Ok(dotenv)
+    }
+}
+
+impl Drop for TempDotenv {
+    fn drop(&mut self) {
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            log::warn!("Failed to remove {}: {error}", self.path.display());
+        }
+    }
+}
+
+/// Replaces `${secrets.NAME}` values with the secrets given, returns the
+/// resolved env and the variables left out mapped to their secret.
+fn resolve_env(
+    env: &HashMap<String, String>,
+    secrets: &HashMap<String, String>,
+) -> (HashMap<String, String>, HashMap<String, String>) {
+    let mut resolved = HashMap::new();
+    let mut unresolved = HashMap::new();
+
+    for (key, value) in env {
+        match get_secret_name(value) {
+            Some(name) => match secrets.get(&name.to_uppercase()) {
+                Some(secret) => {
+                    resolved.insert(key.clone(), secret.clone());
+                }
+                None => {
+                    unresolved.insert(key.clone(), name.to_uppercase());
+                }
+            },
+
+            None => {
+                resolved.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    (resolved, unresolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_env() {
+        let env = HashMap::from([
+            ("PORT".to_string(), "8080".to_string()),
+            ("DATABASE_URL".to_string(), "${secrets.DB}".to_string()),
+            ("TOKEN".to_string(), "${secrets.token}".to_string()),
+            ("CACHE_URL".to_string(), "${secrets.db}".to_string()),
+        ]);
+        let secrets = HashMap::from([("DB".to_string(), "postgres://".to_string())]);
+
+        let (resolved, unresolved) = resolve_env(&env, &secrets);
+
+        assert_eq!(resolved.len(), 3);
+        assert_eq!(resolved["PORT"], "8080");
+        assert_eq!(resolved["DATABASE_URL"], "postgres://");
+        assert_eq!(resolved["CACHE_URL"], "postgres://");
+        assert_eq!(
+            unresolved,
+            HashMap::from([("TOKEN".to_string(), "TOKEN".to_string())])
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::format_env;
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Print the environment variables as a .env file")]
+pub struct Options {
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    for line in format_env(&deployment.config.env) {
+        println!("{line}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Print the value of an environment variable")]
+pub struct Options {
+    #[clap(help = "Name of the variable")]
+    pub key: String,
+
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    let value = deployment.config.env.get(&options.key).ok_or_else(|| {
+        HopError::NotFound(format!(
+            "Deployment `{}` has no variable `{}`",
+            deployment.name, options.key
+        ))
+    })?;
+
+    println!("{value}");
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::update_env;
+use crate::commands::ignite::utils::{env_file_to_map, resolve_deployment};
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Set the environment variables from a .env file, keeping the others")]
+pub struct Options {
+    #[clap(help = "Path to the file", default_value = ".env")]
+    pub file: PathBuf,
+
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(long, help = "Roll out new containers with the change")]
+    pub rollout: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let imported = env_file_to_map(options.file).await?;
+
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    log::info!("Importing {} variables", imported.len());
+
+    let mut env = deployment.config.env.clone();
+    env.extend(imported);
+
+    update_env(&state, &deployment, env, options.rollout).await
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use anyhow::Result;
+use clap::Parser;
+use tabwriter::TabWriter;
+
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "List the environment variables of a deployment")]
+pub struct Options {
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the variables",
+        value_enum,
+        default_value = "text"
+    )]
+    pub output: OutputFormat,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    if options.output == OutputFormat::Json {
+        return print_json(&deployment.config.env);
+    }
+
+    if deployment.config.env.is_empty() {
+        log::info!("Deployment `{}` has no env", deployment.name);
+
+        return Ok(());
+    }
+
+    println!("{}", format_env_table(&deployment.config.env)?.join("\n"));
+
+    Ok(())
+}
+
+fn format_env_table(env: &HashMap<String, String>) -> Result<Vec<String>> {
+    let mut env = env.iter().collect::<Vec<_>>();
+    env.sort();
+
+    let mut tw = TabWriter::new(vec![]);
+
+    writeln!(tw, "KEY\tVALUE")?;
+
+    for (key, value) in env {
+        writeln!(tw, "{key}\t{value}")?;
+    }
+
+    Ok(String::from_utf8(tw.into_inner()?)?
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect())
+}
@@ -0,0 +1,45 @@
+mod exec;
+mod export;
+mod get;
+mod import;
+mod list;
+mod set;
+mod unset;
+mod utils;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::state::State;
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    #[clap(name = "ls", alias = "list")]
+    List(list::Options),
+    Get(get::Options),
+    Set(set::Options),
+    #[clap(alias = "rm", alias = "remove")]
+    Unset(unset::Options),
+    Import(import::Options),
+    Export(export::Options),
+    Exec(exec::Options),
+}
+
+#[derive(Debug, Parser)]
+#[clap(about = "Manage the environment variables of a deployment")]
+pub struct Options {
+    #[clap(subcommand)]
+    pub commands: Commands,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    match options.commands {
+        Commands::List(options) => list::handle(options, state).await,
+        Commands::Get(options) => get::handle(options, state).await,
+        Commands::Set(options) => set::handle(options, state).await,
+        Commands::Unset(options) => unset::handle(options, state).await,
+        Commands::Import(options) => import::handle(options, state).await,
+        Commands::Export(options) => export::handle(options, state).await,
+        Commands::Exec(options) => exec::handle(options, state).await,
+    }
+}
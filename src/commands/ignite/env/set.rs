@@ -0,0 +1,38 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::update_env;
+use crate::commands::ignite::types::Env;
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Set environment variables, keeping the others")]
+pub struct Options {
+    #[clap(
+        help = "Variables to set as `KEY=value`, secrets can be referenced with `${secrets.NAME}`",
+        required = true
+    )]
+    pub env: Vec<Env>,
+
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(long, help = "Roll out new containers with the change")]
+    pub rollout: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    let mut env = deployment.config.env.clone();
+    env.extend(options.env.into_iter().map(|Env(key, value)| (key, value)));
+
+    update_env(&state, &deployment, env, options.rollout).await
+}
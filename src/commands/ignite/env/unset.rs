@@ -0,0 +1,45 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::update_env;
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Remove environment variables")]
+pub struct Options {
+    #[clap(help = "Names of the variables", required = true)]
+    pub keys: Vec<String>,
+
+    #[clap(short, long, help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(long, help = "Roll out new containers with the change")]
+    pub rollout: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "--deployment"),
+    )
+    .await?;
+
+    let mut env = deployment.config.env.clone();
+
+    for key in &options.keys {
+        if env.remove(key).is_none() {
+            log::warn!("Deployment `{}` has no variable `{key}`", deployment.name);
+        }
+    }
+
+    if env.len() == deployment.config.env.len() {
+        log::info!("Nothing to remove");
+
+        return Ok(());
+    }
+
+    update_env(&state, &deployment, env, options.rollout).await
+}
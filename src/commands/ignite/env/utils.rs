@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::commands::ignite::types::{CreateDeployment, Deployment};
+use crate::commands::ignite::utils::{rollout, update_deployment};
+use crate::state::State;
+
+/// Replaces the env of a deployment, rolling it out when asked to.
+pub async fn update_env(
+    state: &State,
+    deployment: &Deployment,
+    env: HashMap<String, String>,
+    with_rollout: bool,
+) -> Result<()> {
+    let deployment = update_deployment(
+        &state.http,
+        &deployment.id,
+        &CreateDeployment {
+            env,
+            ..CreateDeployment::from(deployment.clone())
+        },
+    )
+    .await?;
+
+    if !with_rollout {
+        log::info!(
+            "Updated the env of `{}`, it applies to new containers or after `hop ignite rollout {}`",
+            deployment.name,
+            deployment.name
+        );
+    } else if deployment.can_rollout() {
+        log::info!(
+            "Updated the env of `{}`, rolling out new containers",
+            deployment.name
+        );
+        rollout(&state.http, &deployment.id).await?;
+    } else {
+        log::warn!(
+            "Updated the env of `{}`, it can not be rolled out and applies to new containers",
+            deployment.name
+        );
+    }
+
+    Ok(())
+}
+
+/// `KEY=value` lines sorted by key, secrets stay as `${secrets.NAME}`.
+/// Values that would not survive a round trip through a .env file are quoted.
+pub fn format_env(env: &HashMap<String, String>) -> Vec<String> {
+    let mut env = env.iter().collect::<Vec<_>>();
+    env.sort();
+
+    env.into_iter()
+        .map(|(key, value)| format!("{key}={}", quote_env_value(value)))
+        .collect()
+}
+
+fn quote_env_value(value: &str) -> String {
+    let needs_quotes = value.trim() != value
+        || value.starts_with('\'')
+        || value.contains(['"', '#', '\\', '\n', '\r']);
+
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::ignite::utils::env_file_to_map;
+
+    #[tokio::test]
+    async fn test_format_env_round_trip() {
+        let env = HashMap::from([
+            ("PLAIN".to_string(), "value".to_string()),
+            ("SECRET".to_string(), "${secrets.TOKEN}".to_string()),
+            (
+                "MULTILINE".to_string(),
+                "-----BEGIN-----\nkey\n".to_string(),
+            ),
+            ("HASH".to_string(), "a#b".to_string()),
+            ("SPACES".to_string(), "  padded ".to_string()),
+            ("QUOTES".to_string(), "say \"hi\" \\o/".to_string()),
+        ]);
+
+        let lines = format_env(&env);
+        assert!(lines.contains(&"PLAIN=value".to_string()));
+        assert!(lines.contains(&"MULTILINE=\"-----BEGIN-----\\nkey\\n\"".to_string()));
+
+        let path = std::env::temp_dir().join(format!("hop-env-{}.env", std::process::id()));
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let parsed = env_file_to_map(path.clone()).await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(parsed, env);
+    }
+}
@@ -24,9 +24,9 @@ use crate::commands::ignite::types::{Deployment, Image};
 use crate::commands::ignite::utils::{
     create_deployment, scale, update_deployment_config, WEB_IGNITE_URL,
 };
-use crate::config::LEAP_PROJECT;
 use crate::state::State;
 use crate::store::hopfile::HopFile;
+use crate::utils::prompt;
 use crate::utils::urlify;
 
 #[derive(Debug, Parser)]
@@ -97,7 +97,7 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
 
     compose.validate_and_update(&parent_dir).await?;
 
-    let project = state.ctx.clone().current_project_error();
+    let project = state.ctx.clone().current_project_error()?;
 
     // let deployments = get_all_deployments(&state.http, &project.id).await?;
 
@@ -214,10 +214,8 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     let build_localy = if has_unbuilt {
         log::info!("Some of the services in the compose file require building. They can be built locally or on our build servers");
 
-        let answer = dialoguer::Confirm::new()
-            .with_prompt("Would you like to build them locally?")
-            .default(true)
-            .interact()?;
+        let answer = !prompt::is_interactive()
+            || prompt::confirm("Would you like to build them locally?", true, "--no-input")?;
 
         println!();
 
@@ -229,9 +227,8 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     // connect to leap here so no logs interfere with the deploy
     let mut leap = LeapEdge::new(LeapOptions {
         token: Some(&state.ctx.current.clone().unwrap().leap_token),
-        project: &std::env::var("LEAP_PROJECT").unwrap_or_else(|_| LEAP_PROJECT.to_string()),
-        ws_url: &std::env::var("LEAP_WS_URL")
-            .unwrap_or_else(|_| LeapOptions::default().ws_url.to_string()),
+        project: &state.ctx.leap_project(),
+        ws_url: &state.ctx.leap_ws_url(),
     })
     .await?;
 
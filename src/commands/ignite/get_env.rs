@@ -1,41 +1,27 @@
 use std::io::Write;
 
-use anyhow::{ensure, Result};
+use anyhow::Result;
 use clap::Parser;
 
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments, get_deployment};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::commands::secrets::utils::get_secret_name;
 use crate::state::State;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "Get current deployments env values")]
 pub struct Options {
-    #[clap(help = "ID of the deployment to get env values")]
+    #[clap(help = "ID or name of the deployment to get env values")]
     pub deployment: Option<String>,
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment = match options.deployment {
-        Some(id) => get_deployment(&state.http, &id).await?,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
-
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()
-                .expect("Failed to select deployment")
-                .expect("No deployment selected");
-
-            deployments[idx].clone()
-        }
-    };
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
 
     let mut buff = vec![];
 
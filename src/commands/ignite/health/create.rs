@@ -1,14 +1,15 @@
-use anyhow::{ensure, Result};
+use anyhow::Result;
 use clap::Parser;
 
 use super::utils::{create_health_check, create_health_check_config};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "Create Health Checks for a deployment")]
 pub struct Options {
-    #[clap(name = "deployment", help = "ID of the deployment")]
+    #[clap(name = "deployment", help = "ID or name of the deployment")]
     pub deployment: Option<String>,
 
     #[clap(flatten)]
@@ -37,26 +38,13 @@ pub struct HealthCheckCreate {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment_id = match options.deployment {
-        Some(id) => id,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
-
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()?
-                .ok_or_else(|| anyhow::anyhow!("No deployment selected"))?;
-
-            deployments[idx].id.clone()
-        }
-    };
+    let deployment_id = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?
+    .id;
 
     let health_config = create_health_check_config(options.health_check)?;
 
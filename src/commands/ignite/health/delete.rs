@@ -2,8 +2,9 @@ use anyhow::{bail, ensure, Result};
 use clap::Parser;
 
 use super::utils::{delete_health_check, format_health_checks, get_all_health_checks};
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Delete a Health Check")]
@@ -19,28 +20,18 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     let health_checks = if !options.health_checks.is_empty() {
         options.health_checks
     } else {
-        let project_id = state.ctx.current_project_error().id;
+        let deployment =
+            resolve_deployment(&state, None, Arg::new("health checks", "<HEATH-CHECKS>")).await?;
 
-        let deployments = get_all_deployments(&state.http, &project_id).await?;
-        ensure!(!deployments.is_empty(), "No deployments found");
-        let deployments_fmt = format_deployments(&deployments, false);
-
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a deployment")
-            .items(&deployments_fmt)
-            .default(0)
-            .interact_opt()?
-            .ok_or_else(|| anyhow::anyhow!("No deployment selected"))?;
-
-        let health_checks = get_all_health_checks(&state.http, &deployments[idx].id).await?;
+        let health_checks = get_all_health_checks(&state.http, &deployment.id).await?;
         ensure!(!health_checks.is_empty(), "No health checks found");
         let health_checks_fmt = format_health_checks(&health_checks, false);
 
-        let idxs = dialoguer::MultiSelect::new()
-            .with_prompt("Select a health check")
-            .items(&health_checks_fmt)
-            .interact_opt()?
-            .ok_or_else(|| anyhow::anyhow!("No health check selected"))?;
+        let idxs = prompt::multi_select(
+            "Select a health check",
+            &health_checks_fmt,
+            Arg::new("health checks", "<HEATH-CHECKS>"),
+        )?;
 
         health_checks
             .iter()
@@ -51,13 +42,14 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     };
 
     if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt(format!(
+        && !prompt::confirm(
+            format!(
                 "Are you sure you want to delete {} Health Checks?",
                 health_checks.len()
-            ))
-            .interact_opt()?
-            .unwrap_or(false)
+            ),
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted");
     }
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -28,13 +30,23 @@ impl Default for CreateHealthCheck {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum HealthCheckType {
     Liveness,
 }
 
-#[derive(Debug, Deserialize)]
+impl Display for HealthCheckType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap().replace('"', "")
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct HealthCheck {
     pub id: String,
     pub deployment_id: String,
@@ -61,7 +73,7 @@ pub struct MultipleHealthChecks {
     pub health_checks: Vec<HealthCheck>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct HealthCheckState {
     pub state: String,
     pub container_id: String,
@@ -9,6 +9,7 @@ use super::types::{
     MultipleHealthChecks, SingleHealthCheck,
 };
 use crate::state::http::HttpClient;
+use crate::utils::prompt::{self, Arg};
 use crate::utils::relative_time;
 
 pub fn create_health_check_config(
@@ -57,6 +58,11 @@ pub fn update_config_from_args(
 }
 
 pub fn update_config_from_guided(config: &mut CreateHealthCheck) -> Result<()> {
+    prompt::ensure_interactive(Arg::new(
+        "health check config",
+        "the config as flags, see `--help`",
+    ))?;
+
     config.port = dialoguer::Input::<u16>::new()
         .with_prompt("Port of the health check")
         .default(config.port)
@@ -1,12 +1,17 @@
 pub mod builds;
+mod compare;
 pub mod create;
 mod delete;
+mod describe;
+mod edit;
+mod env;
 pub mod from_compose;
 mod get_env;
-mod health;
+pub mod health;
 mod list;
 mod promote;
 pub mod rollout;
+mod run;
 mod scale;
 mod templates;
 pub mod types;
@@ -29,9 +34,16 @@ pub enum Commands {
     #[clap(alias = "rollouts")]
     Rollout(rollout::Options),
     Update(update::Options),
+    Edit(edit::Options),
+    #[clap(alias = "inspect")]
+    Describe(describe::Options),
+    #[clap(alias = "diff")]
+    Compare(compare::Options),
     Scale(scale::Options),
+    Run(run::Options),
     #[clap(name = "get-env")]
     GetEnv(get_env::Options),
+    Env(env::Options),
     #[clap(alias = "compose")]
     FromCompose(from_compose::Options),
     #[clap(alias = "check")]
@@ -63,9 +75,14 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         Commands::Create(options) => create::handle(options, state).await,
         Commands::Delete(options) => delete::handle(options, state).await,
         Commands::Update(options) => update::handle(options, state).await,
+        Commands::Edit(options) => edit::handle(options, state).await,
+        Commands::Describe(options) => describe::handle(options, state).await,
+        Commands::Compare(options) => compare::handle(options, state).await,
         Commands::Rollout(options) => rollout::handle(options, state).await,
         Commands::Scale(options) => scale::handle(options, state).await,
+        Commands::Run(options) => run::handle(options, state).await,
         Commands::GetEnv(options) => get_env::handle(options, state).await,
+        Commands::Env(options) => env::handle(options, state).await,
         Commands::Health(options) => health::handle(options, state).await,
         Commands::Containers(options) => super::containers::handle(options, state).await,
         Commands::Gateways(options) => super::gateways::handle(options, state).await,
@@ -1,15 +1,16 @@
 use anyhow::{ensure, Result};
 use clap::Parser;
 
-use super::utils::{format_deployments, get_all_deployments, promote};
+use super::utils::{promote, resolve_deployment};
 use crate::commands::ignite::builds::types::BuildState;
 use crate::commands::ignite::builds::utils::get_all_builds;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Rollback containers in a deployment")]
 pub struct Options {
-    #[clap(help = "ID of the deployment")]
+    #[clap(help = "ID or name of the deployment")]
     pub deployment: Option<String>,
 
     #[clap(help = "ID of the build to rollback to")]
@@ -17,26 +18,13 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment_id = match options.deployment {
-        Some(id) => id,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
-
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()?
-                .ok_or_else(|| anyhow::anyhow!("No build selected"))?;
-
-            deployments[idx].id.clone()
-        }
-    };
+    let deployment_id = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?
+    .id;
 
     let build_id = match options.build {
         Some(id) => id,
@@ -49,12 +37,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
                 .collect::<Vec<_>>();
             ensure!(!builds.is_empty(), "No successful builds found");
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a build")
-                .items(&builds.iter().map(|b| &b.id).collect::<Vec<_>>())
-                .default(0)
-                .interact_opt()?
-                .ok_or_else(|| anyhow::anyhow!("No build selected"))?;
+            let idx = prompt::select(
+                "Select a build",
+                &builds.iter().map(|b| &b.id).collect::<Vec<_>>(),
+                0,
+                Arg::new("build", "<BUILD>"),
+            )?;
 
             builds[idx].id.clone()
         }
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use futures_util::StreamExt;
+
+use crate::commands::containers::types::{Container, ContainerOverrides, ContainerState};
+use crate::commands::containers::utils::{
+    create_containers_with_overrides, delete_container, format_logs, get_container,
+};
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::arisu::{ArisuClient, ArisuMessage};
+use crate::utils::prompt::Arg;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// How long to keep printing logs after the container exited, logs can
+/// arrive after the state changed
+const LOG_DRAIN_IDLE: Duration = Duration::from_secs(1);
+const LOG_DRAIN_MAX: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Parser)]
+#[clap(about = "Run a one-off container in a deployment and wait for it to exit")]
+pub struct Options {
+    #[clap(help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        last = true,
+        help = "Command to run instead of the deployment's default, passed after `--`"
+    )]
+    pub command: Vec<String>,
+
+    #[clap(long, help = "Do not delete the container after it exits")]
+    pub keep: bool,
+
+    #[clap(short, long, help = "Show timestamps")]
+    pub timestamps: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
+
+    let overrides = if options.command.is_empty() {
+        None
+    } else {
+        Some(ContainerOverrides {
+            cmd: Some(options.command.clone()),
+        })
+    };
+
+    let container = create_containers_with_overrides(&state.http, &deployment.id, 1, overrides)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No container was created"))?;
+
+    log::info!(
+        "Running container `{}` in deployment `{}`",
+        container.id,
+        deployment.name
+    );
+
+    let finished = follow(&state, &container.id, options.timestamps).await;
+
+    if !options.keep {
+        cleanup(&state, &container.id).await;
+    }
+
+    let finished = finished?;
+
+    let exit_code = finished.exit_code.unwrap_or(match finished.state {
+        ContainerState::Exited => 0,
+        _ => 1,
+    });
+
+    if exit_code != 0 {
+        return Err(HopError::Exited(
+            format!(
+                "Container `{}` finished with state `{}` (exit code {exit_code})",
+                container.id, finished.state
+            ),
+            exit_code,
+        )
+        .into());
+    }
+
+    log::info!("Container `{}` exited successfully", container.id);
+
+    Ok(())
+}
+
+/// Prints the logs of the container until it exits, and the logs that
+/// arrive shortly after.
+async fn follow(state: &State, container_id: &str, timestamps: bool) -> Result<Container> {
+    let token = state
+        .token()
+        .ok_or_else(|| anyhow!("No auth token found."))?;
+
+    let mut arisu = ArisuClient::new(container_id, &token).await?;
+    let mut poll = tokio::time::interval(POLL_INTERVAL);
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let print = |message: ArisuMessage| match message {
+        ArisuMessage::ServiceMessage(data) => log::debug!("Service: {data}"),
+        ArisuMessage::Out(log) => {
+            print!("{}", format_logs(&[log], true, timestamps, false)[0]);
+        }
+    };
+
+    let finished = loop {
+        tokio::select! {
+            Some(message) = arisu.next() => print(message),
+
+            _ = poll.tick() => {
+                let current = get_container(&state.http, container_id).await?;
+
+                if current.has_exited() {
+                    break current;
+                }
+            }
+
+            _ = &mut ctrl_c => {
+                println!();
+
+                bail!("Interrupted while waiting for container `{container_id}`");
+            }
+        }
+    };
+
+    let drain = tokio::time::sleep(LOG_DRAIN_MAX);
+    tokio::pin!(drain);
+
+    loop {
+        tokio::select! {
+            message = tokio::time::timeout(LOG_DRAIN_IDLE, arisu.next()) => match message {
+                Ok(Some(message)) => print(message),
+                // the stream ended or went quiet
+                _ => break,
+            },
+
+            _ = &mut drain => break,
+            _ = &mut ctrl_c => break,
+        }
+    }
+
+    Ok(finished)
+}
+
+async fn cleanup(state: &State, container_id: &str) {
+    // ephemeral containers delete themselves, so this is allowed to fail
+    if let Err(err) = delete_container(&state.http, container_id).await {
+        log::debug!("Failed to delete container `{container_id}`: {err}");
+    } else {
+        log::info!("Deleted container `{container_id}`");
+    }
+}
@@ -1,50 +1,95 @@
-use anyhow::{ensure, Result};
+use anyhow::{bail, ensure, Result};
 use clap::Parser;
 
-use super::utils::{format_deployments, get_all_deployments, scale};
-use crate::commands::ignite::utils::get_deployment;
+use super::types::{Autoscaling, CreateDeployment};
+use super::utils::{resolve_deployment, scale};
+use crate::commands::ignite::utils::{update_deployment, validate_autoscaling};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Scale a deployment")]
 pub struct Options {
-    #[clap(help = "ID of the deployment to scale")]
+    #[clap(help = "ID or name of the deployment to scale")]
     pub deployment: Option<String>,
 
     #[clap(help = "Number of replicas to scale to")]
     pub scale: Option<u64>,
+
+    #[clap(
+        long,
+        help = "Minimum amount of containers, for autoscaled deployments"
+    )]
+    pub min: Option<u64>,
+
+    #[clap(
+        long,
+        help = "Maximum amount of containers, for autoscaled deployments"
+    )]
+    pub max: Option<u64>,
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let deployment = match options.deployment {
-        Some(id) => get_deployment(&state.http, &id).await?,
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
 
-        None => {
-            let project_id = state.ctx.current_project_error().id;
+    if deployment.is_autoscaled() {
+        ensure!(
+            options.scale.is_none(),
+            "Deployment `{}` is autoscaled, adjust its bounds with `--min` and `--max` instead",
+            deployment.name
+        );
 
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
+        let current = deployment.config.autoscaling.clone().unwrap_or_default();
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()
-                .expect("Failed to select deployment")
-                .expect("No deployment selected");
+        let autoscaling = Autoscaling {
+            min_containers: options.min.unwrap_or(current.min_containers),
+            max_containers: options.max.unwrap_or(current.max_containers),
+            ..current
+        };
 
-            deployments[idx].clone()
+        if options.min.is_none() && options.max.is_none() {
+            bail!(
+                "Deployment `{}` is autoscaled between {} containers, pass `--min` and/or `--max` to adjust the bounds",
+                deployment.name,
+                current
+            );
         }
-    };
+
+        validate_autoscaling(&autoscaling)?;
+
+        update_deployment(
+            &state.http,
+            &deployment.id,
+            &CreateDeployment {
+                autoscaling: Some(autoscaling.clone()),
+                ..CreateDeployment::from(deployment.clone())
+            },
+        )
+        .await?;
+
+        log::info!("Autoscaling deployment between {autoscaling} containers");
+
+        return Ok(());
+    }
+
+    ensure!(
+        options.min.is_none() && options.max.is_none(),
+        "`--min` and `--max` can only be used on autoscaled deployments"
+    );
 
     let scale_count = match options.scale {
         Some(scale) => scale,
-        None => dialoguer::Input::<u64>::new()
-            .with_prompt("Enter the number of containers to scale to")
-            .default(deployment.container_count)
-            .interact()
-            .expect("Failed to select a deployment"),
+        None => prompt::input::<u64>(
+            "Enter the number of containers to scale to",
+            Arg::new("scale", "<SCALE>"),
+        )?
+        .default(deployment.container_count)
+        .interact()?,
     };
 
     scale(&state.http, &deployment.id, scale_count).await?;
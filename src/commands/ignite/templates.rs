@@ -9,6 +9,7 @@ use crate::commands::ignite::utils::{
     create_deployment, format_premade, get_premade, update_deployment_config, WEB_IGNITE_URL,
 };
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 use crate::utils::urlify;
 
 #[derive(Debug, Parser, Default, PartialEq, Clone)]
@@ -22,7 +23,7 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project = state.ctx.current_project_error();
+    let project = state.ctx.current_project_error()?;
 
     let premades = get_premade(&state.http).await?;
 
@@ -34,11 +35,12 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
     } else {
         let premade_fmt = format_premade(&premades, false)?;
 
-        let selection = dialoguer::Select::new()
-            .with_prompt("Select a template")
-            .items(&premade_fmt)
-            .default(0)
-            .interact()?;
+        let selection = prompt::select(
+            "Select a template",
+            &premade_fmt,
+            0,
+            Arg::new("template", "<TEMPLATE>"),
+        )?;
 
         &premades[selection]
     };
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::commands::containers::types::ContainerType;
 use crate::utils::parse_key_val;
+use crate::utils::resolve::Resource;
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct Vgpu {
@@ -131,7 +132,25 @@ impl Display for ScalingStrategy {
 
 impl ScalingStrategy {
     pub fn values() -> Vec<Self> {
-        vec![Self::Manual]
+        vec![Self::Manual, Self::Autoscaled]
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default)]
+pub struct Autoscaling {
+    pub min_containers: u64,
+    pub max_containers: u64,
+    /// percentage of CPU usage to scale at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_cpu_usage: Option<u8>,
+    /// percentage of memory usage to scale at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_memory_usage: Option<u8>,
+}
+
+impl Display for Autoscaling {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.min_containers, self.max_containers)
     }
 }
 
@@ -149,6 +168,7 @@ pub struct Config {
     pub image: Image,
     pub env: HashMap<String, String>,
     pub container_strategy: ScalingStrategy,
+    pub autoscaling: Option<Autoscaling>,
     pub resources: Resources,
     pub restart_policy: Option<RestartPolicy>,
     pub entrypoint: Option<Vec<String>>,
@@ -174,6 +194,18 @@ pub struct Metadata {
     pub container_port_mappings: HashMap<String, Vec<String>>,
 }
 
+impl Resource for Deployment {
+    const KIND: &'static str = "deployment";
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+}
+
 impl Deployment {
     pub fn is_ephemeral(&self) -> bool {
         self.config.type_ == ContainerType::Ephemeral
@@ -187,6 +219,10 @@ impl Deployment {
         self.config.container_strategy == ScalingStrategy::Manual
             && self.config.type_ != ContainerType::Stateful
     }
+
+    pub fn is_autoscaled(&self) -> bool {
+        self.config.container_strategy == ScalingStrategy::Autoscaled
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -199,10 +235,13 @@ pub struct MultipleDeployments {
     pub deployments: Vec<Deployment>,
 }
 
-#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct CreateDeployment {
     pub restart_policy: Option<RestartPolicy>,
     pub container_strategy: ScalingStrategy,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autoscaling: Option<Autoscaling>,
+    #[serde(default)]
     pub env: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<Image>,
@@ -224,6 +263,7 @@ impl From<Deployment> for CreateDeployment {
         Self {
             restart_policy: deployment.config.restart_policy,
             container_strategy: deployment.config.container_strategy,
+            autoscaling: deployment.config.autoscaling,
             env: deployment.config.env,
             image: Some(deployment.config.image),
             name: Some(deployment.name),
@@ -409,10 +449,20 @@ pub struct RolloutEvent {
     pub state: RolloutState,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum RolloutState {
     Pending,
     Finished,
     Failed,
 }
+
+impl Display for RolloutState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap().replace('"', "")
+        )
+    }
+}
@@ -1,18 +1,18 @@
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, Result};
 use clap::Parser;
 
 use super::create::Options as CreateOptions;
 
 use crate::commands::ignite::utils::{
-    format_deployments, get_all_deployments, get_deployment, rollout, scale, update_deployment,
-    update_deployment_config,
+    resolve_deployment, rollout, scale, update_deployment, update_deployment_config,
 };
 use crate::state::State;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "Update a deployment")]
 pub struct Options {
-    #[clap(help = "ID of the deployment to update")]
+    #[clap(help = "ID or name of the deployment to update")]
     deployment: Option<String>,
 
     #[clap(flatten)]
@@ -23,27 +23,12 @@ pub struct Options {
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let old_deployment = match options.deployment {
-        Some(id) => get_deployment(&state.http, &id).await?,
-
-        None => {
-            let project_id = state.ctx.current_project_error().id;
-
-            let deployments = get_all_deployments(&state.http, &project_id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found");
-            let deployments_fmt = format_deployments(&deployments, false);
-
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()
-                .expect("Failed to select deployment")
-                .expect("No deployment selected");
-
-            deployments[idx].clone()
-        }
-    };
+    let old_deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
 
     let is_visual = options.config == CreateOptions::default();
 
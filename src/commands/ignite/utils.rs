@@ -14,16 +14,25 @@ use super::types::{
     ScaleRequest, SingleDeployment, Tier, Tiers,
 };
 use crate::commands::containers::types::{ContainerOptions, ContainerType};
-use crate::commands::ignite::create::Options;
+use crate::commands::ignite::create::{AutoscalingConfig, Options};
 use crate::commands::ignite::types::{
-    Image, RamSizes, Resources, RestartPolicy, RolloutResponse, ScalingStrategy, VolumeFs,
+    Autoscaling, Image, RamSizes, Resources, RestartPolicy, RolloutResponse, ScalingStrategy,
+    VolumeFs,
 };
+use crate::error::HopError;
 use crate::state::http::HttpClient;
+use crate::state::State;
+use crate::store::hopfile::HopFile;
+use crate::utils::prompt::{self, Arg};
+use crate::utils::resolve;
 use crate::utils::size::parse_size;
 use crate::utils::{ask_question_iter, parse_key_val};
 
 pub const WEB_IGNITE_URL: &str = "https://console.hop.io/ignite";
 
+const MAX_CONTAINERS: u64 = 10;
+const DEFAULT_TARGET_USAGE: u8 = 80;
+
 pub async fn get_all_deployments(http: &HttpClient, project_id: &str) -> Result<Vec<Deployment>> {
     let response = http
         .request::<MultipleDeployments>(
@@ -46,6 +55,54 @@ pub async fn get_deployment(http: &HttpClient, deployment_id: &str) -> Result<De
     Ok(response.deployment)
 }
 
+/// Resolves a deployment from an ID or a name. Without one it uses the
+/// hopfile in the current directory, then asks the user to pick one.
+pub async fn resolve_deployment(
+    state: &State,
+    query: Option<&str>,
+    arg: Arg<'_>,
+) -> Result<Deployment> {
+    let query = match query {
+        Some(query) => query.to_string(),
+
+        None => match HopFile::find_current().await {
+            Some(hopfile) => {
+                // debug, info logs go to stdout and would break JSON output
+                log::debug!(
+                    "Using deployment `{}` from the hopfile",
+                    hopfile.config.deployment_id
+                );
+
+                hopfile.config.deployment_id
+            }
+
+            None => return pick_deployment(state, arg).await,
+        },
+    };
+
+    if resolve::is_id::<Deployment>(&query) {
+        return get_deployment(&state.http, &query).await;
+    }
+
+    let project = state.ctx.clone().current_project_error()?;
+    let deployments = get_all_deployments(&state.http, &project.id).await?;
+
+    resolve::find(&deployments, &query).cloned()
+}
+
+/// Asks the user to pick one of the deployments in the current project.
+pub async fn pick_deployment(state: &State, arg: Arg<'_>) -> Result<Deployment> {
+    let project = state.ctx.clone().current_project_error()?;
+
+    let deployments = get_all_deployments(&state.http, &project.id).await?;
+    ensure!(!deployments.is_empty(), "No deployments found");
+    let deployments_fmt = format_deployments(&deployments, false);
+
+    let idx = prompt::fuzzy_select("Select a deployment", &deployments_fmt, arg)?;
+
+    Ok(deployments[idx].clone())
+}
+
 pub async fn create_deployment(
     http: &HttpClient,
     project_id: &str,
@@ -111,6 +168,16 @@ pub async fn rollout(http: &HttpClient, deployment_id: &str) -> Result<RolloutEv
     Ok(response)
 }
 
+pub async fn get_rollout(http: &HttpClient, rollout_id: &str) -> Result<RolloutEvent> {
+    let response = http
+        .request::<RolloutResponse>("GET", &format!("/ignite/rollouts/{rollout_id}"), None)
+        .await?
+        .ok_or_else(|| anyhow!("Failed to parse response"))?
+        .rollout;
+
+    Ok(response)
+}
+
 pub async fn promote(http: &HttpClient, deployment_id: &str, build_id: &str) -> Result<()> {
     http.request::<Value>(
         "POST",
@@ -170,11 +237,18 @@ pub fn format_deployments(deployments: &Vec<Deployment>, title: bool) -> Vec<Str
     for deployment in deployments {
         writeln!(
             &mut tw,
-            "{}\t{}\t{}/{}\t{}\t{}\t{}\t{}",
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
             deployment.name,
             deployment.id,
-            deployment.container_count,
-            deployment.target_container_count,
+            match deployment.config.autoscaling {
+                Some(ref autoscaling) if deployment.is_autoscaled() => {
+                    format!("{} ({autoscaling})", deployment.container_count)
+                }
+                _ => format!(
+                    "{}/{}",
+                    deployment.container_count, deployment.target_container_count
+                ),
+            },
             deployment.created_at,
             deployment.config.type_,
             deployment.config.container_strategy,
@@ -211,6 +285,11 @@ pub async fn update_deployment_config(
         .map(|s| s.replace(['_', ' ', '.'], "-").to_lowercase());
 
     if is_visual {
+        prompt::ensure_interactive(Arg::new(
+            "deployment config",
+            "the config as flags, see `--help`",
+        ))?;
+
         update_config_visual(
             http,
             options,
@@ -340,9 +419,34 @@ async fn update_config_args(
         }
     }
 
-    deployment_config.container_strategy = ScalingStrategy::Manual;
+    deployment_config.container_strategy =
+        options.config.scaling_strategy.clone().unwrap_or_else(|| {
+            if is_update {
+                deployment_config.container_strategy.clone()
+            } else {
+                ScalingStrategy::Manual
+            }
+        });
+
+    if deployment_config.container_strategy == ScalingStrategy::Autoscaled {
+        ensure!(
+            deployment_config.type_ != Some(ContainerType::Stateful),
+            "Stateful deployments cannot be autoscaled"
+        );
+
+        let autoscaling = get_autoscaling_from_args(
+            &options.config.autoscaling,
+            deployment_config.autoscaling.take(),
+        )?;
+
+        container_options.containers = None;
+        container_options.min_containers = Some(autoscaling.min_containers);
+        container_options.max_containers = Some(autoscaling.max_containers);
+
+        deployment_config.autoscaling = Some(autoscaling);
+    } else if deployment_config.type_ != Some(ContainerType::Stateful) {
+        deployment_config.autoscaling = None;
 
-    if deployment_config.type_ != Some(ContainerType::Stateful) {
         container_options.containers = Some(
         options
             .config
@@ -550,9 +654,28 @@ async fn update_config_visual(
         deployment_config.volume = Some(volume);
     }
 
-    deployment_config.container_strategy = ScalingStrategy::Manual;
+    deployment_config.container_strategy =
+        if deployment_config.type_ == Some(ContainerType::Stateful) {
+            ScalingStrategy::Manual
+        } else {
+            ask_question_iter(
+                "Scaling strategy",
+                &ScalingStrategy::values(),
+                Some(deployment_config.container_strategy.clone()),
+            )?
+        };
+
+    if deployment_config.container_strategy == ScalingStrategy::Autoscaled {
+        let autoscaling = get_autoscaling_from_input(deployment_config.autoscaling.take())?;
+
+        container_options.containers = None;
+        container_options.min_containers = Some(autoscaling.min_containers);
+        container_options.max_containers = Some(autoscaling.max_containers);
+
+        deployment_config.autoscaling = Some(autoscaling);
+    } else if deployment_config.type_ != Some(ContainerType::Stateful) {
+        deployment_config.autoscaling = None;
 
-    if deployment_config.type_ != Some(ContainerType::Stateful) {
         container_options.containers = Some(
             dialoguer::Input::<u64>::new()
                 .with_prompt("Container amount to start")
@@ -671,6 +794,135 @@ async fn update_config_visual(
     Ok((deployment_config.clone(), container_options.clone()))
 }
 
+fn get_autoscaling_from_args(
+    args: &AutoscalingConfig,
+    current: Option<Autoscaling>,
+) -> Result<Autoscaling> {
+    let current = current.as_ref();
+
+    let autoscaling = Autoscaling {
+        min_containers: args
+            .min_containers
+            .or_else(|| current.map(|a| a.min_containers))
+            .ok_or_else(|| {
+                anyhow!(
+                    "The argument '--min-containers <MIN_CONTAINERS>' requires a value but none was supplied"
+                )
+            })?,
+        max_containers: args
+            .max_containers
+            .or_else(|| current.map(|a| a.max_containers))
+            .ok_or_else(|| {
+                anyhow!(
+                    "The argument '--max-containers <MAX_CONTAINERS>' requires a value but none was supplied"
+                )
+            })?,
+        target_cpu_usage: args
+            .target_cpu
+            .or_else(|| current.and_then(|a| a.target_cpu_usage)),
+        target_memory_usage: args
+            .target_memory
+            .or_else(|| current.and_then(|a| a.target_memory_usage)),
+    };
+
+    validate_autoscaling(&autoscaling)?;
+
+    Ok(autoscaling)
+}
+
+fn get_autoscaling_from_input(current: Option<Autoscaling>) -> Result<Autoscaling> {
+    let current = current.unwrap_or(Autoscaling {
+        min_containers: 1,
+        max_containers: 3,
+        target_cpu_usage: Some(DEFAULT_TARGET_USAGE),
+        target_memory_usage: Some(DEFAULT_TARGET_USAGE),
+    });
+
+    let min_containers = dialoguer::Input::<u64>::new()
+        .with_prompt("Minimum amount of containers")
+        .default(current.min_containers)
+        .validate_with(|min: &u64| -> Result<(), &str> {
+            if *min > MAX_CONTAINERS {
+                Err("Container amount must be less than or equal to 10")
+            } else {
+                Ok(())
+            }
+        })
+        .interact_text()?;
+
+    let max_containers = dialoguer::Input::<u64>::new()
+        .with_prompt("Maximum amount of containers")
+        .default(current.max_containers.max(min_containers).max(1))
+        .validate_with(|max: &u64| -> Result<(), &str> {
+            if *max == 0 || *max < min_containers {
+                Err("Maximum must be greater than 0 and at least the minimum")
+            } else if *max > MAX_CONTAINERS {
+                Err("Container amount must be less than or equal to 10")
+            } else {
+                Ok(())
+            }
+        })
+        .interact_text()?;
+
+    let target_cpu_usage = dialoguer::Input::<u8>::new()
+        .with_prompt("Target CPU usage (%)")
+        .default(current.target_cpu_usage.unwrap_or(DEFAULT_TARGET_USAGE))
+        .validate_with(validate_target_usage)
+        .interact_text()?;
+
+    let target_memory_usage = dialoguer::Input::<u8>::new()
+        .with_prompt("Target memory usage (%)")
+        .default(current.target_memory_usage.unwrap_or(DEFAULT_TARGET_USAGE))
+        .validate_with(validate_target_usage)
+        .interact_text()?;
+
+    Ok(Autoscaling {
+        min_containers,
+        max_containers,
+        target_cpu_usage: Some(target_cpu_usage),
+        target_memory_usage: Some(target_memory_usage),
+    })
+}
+
+pub fn validate_autoscaling(autoscaling: &Autoscaling) -> Result<()> {
+    ensure!(
+        autoscaling.max_containers > 0,
+        "Maximum amount of containers must be greater than 0"
+    );
+
+    ensure!(
+        autoscaling.min_containers <= autoscaling.max_containers,
+        "Minimum amount of containers must be less than or equal to the maximum"
+    );
+
+    ensure!(
+        autoscaling.max_containers <= MAX_CONTAINERS,
+        "Container amount must be less than or equal to {MAX_CONTAINERS}"
+    );
+
+    for usage in [
+        autoscaling.target_cpu_usage,
+        autoscaling.target_memory_usage,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        if let Err(why) = validate_target_usage(&usage) {
+            bail!("{why}")
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_target_usage(usage: &u8) -> Result<(), &'static str> {
+    if *usage == 0 || *usage > 100 {
+        Err("Target usage must be a percentage between 1 and 100")
+    } else {
+        Ok(())
+    }
+}
+
 fn get_multiple_envs() -> Result<HashMap<String, String>> {
     let mut env = HashMap::new();
 
@@ -728,7 +980,7 @@ fn get_env_from_input() -> Option<(String, String)> {
     Some((key, value))
 }
 
-fn validate_deployment_name(name: &str) -> Result<()> {
+pub fn validate_deployment_name(name: &str) -> Result<()> {
     const MAX_LENGTH: usize = 20;
 
     ensure!(
@@ -753,7 +1005,7 @@ fn validate_deployment_name(name: &str) -> Result<()> {
     Ok(())
 }
 
-fn validate_cpu_count(cpu: &f64) -> Result<(), &'static str> {
+pub fn validate_cpu_count(cpu: &f64) -> Result<(), &'static str> {
     if cpu < &0.5 {
         Err("CPUs must be at least 0.5")
     } else if cpu % 0.5 != 0.0 {
@@ -772,19 +1024,19 @@ pub fn get_shell_array(entrypoint: &str) -> Vec<String> {
         .collect()
 }
 
-pub async fn env_file_to_map(path: PathBuf) -> HashMap<String, String> {
+pub async fn env_file_to_map(path: PathBuf) -> Result<HashMap<String, String>> {
     let mut env = HashMap::new();
 
-    assert!(
+    ensure!(
         path.exists(),
         "Could not find .env file at {}",
         path.display()
     );
 
-    let file = fs::read_to_string(path).await.unwrap();
-    let lines = file.lines();
-
-    for line in lines {
+    let file = fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    for (index, line) in file.lines().enumerate() {
         let line = line.trim();
 
         // ignore comments
@@ -796,15 +1048,52 @@ pub async fn env_file_to_map(path: PathBuf) -> HashMap<String, String> {
             continue;
         }
 
-        match parse_key_val(line) {
+        match parse_key_val::<String, String>(line) {
             Ok((key, value)) => {
-                env.insert(key, value);
+                env.insert(key, unquote_env_value(&value));
+            }
+            Err(e) => {
+                return Err(HopError::Validation(format!(
+                    "Line {} of {} is not valid: {e}",
+                    index + 1,
+                    path.display()
+                ))
+                .into())
             }
-            Err(e) => log::warn!("Failed to parse env file line: {}", e),
         }
     }
 
-    env
+    Ok(env)
+}
+
+/// Removes the quotes around a .env value, unescaping double quoted ones.
+fn unquote_env_value(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return value[1..value.len() - 1].to_string();
+    }
+
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return value.to_string();
+    }
+
+    let mut unquoted = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unquoted.push('\n'),
+            Some('r') => unquoted.push('\r'),
+            Some(other) => unquoted.push(other),
+            None => unquoted.push('\\'),
+        }
+    }
+
+    unquoted
 }
 
 pub fn format_premade(premades: &[Premade], title: bool) -> Result<Vec<String>> {
@@ -846,4 +1135,16 @@ mod test {
         );
         assert_eq!(entrypoint_array.next(), None);
     }
+
+    #[tokio::test]
+    async fn test_env_file_to_map_invalid_line() {
+        let path = std::env::temp_dir().join(format!("hop-invalid-{}.env", std::process::id()));
+        std::fs::write(&path, "# comment\nPORT=8080\n\nNOT A VARIABLE\n").unwrap();
+
+        let error = env_file_to_map(path.clone()).await.unwrap_err();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(HopError::exit_code_of(&error), 7);
+        assert!(error.to_string().starts_with("Line 4 of "));
+    }
 }
@@ -4,11 +4,12 @@ use std::path::PathBuf;
 use anyhow::{ensure, Result};
 use clap::Parser;
 
-use crate::commands::ignite::utils::{format_deployments, get_all_deployments, get_deployment};
+use crate::commands::ignite::utils::{pick_deployment, resolve_deployment};
 use crate::commands::projects::utils::format_project;
 use crate::config::EXEC_NAME;
 use crate::state::State;
 use crate::store::hopfile::HopFile;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "Link an existing deployment to a hopfile")]
@@ -19,7 +20,7 @@ pub struct Options {
     )]
     path: Option<PathBuf>,
 
-    #[clap(help = "ID of the deployment")]
+    #[clap(help = "ID or name of the deployment")]
     deployment: Option<String>,
 }
 
@@ -36,32 +37,16 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         log::warn!("A hopfile was found {dir:?}, did you mean to `{EXEC_NAME} deploy`?");
     }
 
-    let project = state.ctx.current_project_error();
+    let project = state.ctx.clone().current_project_error()?;
 
     log::info!("Project: {}", format_project(&project));
 
-    let deployment = match options.deployment {
-        Some(id) => get_deployment(&state.http, &id).await?,
-
-        None => {
-            let deployments = get_all_deployments(&state.http, &project.id).await?;
-
-            let deployments_fmt = format_deployments(&deployments, false);
+    let arg = Arg::new("deployment", "<DEPLOYMENT>");
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a deployment")
-                .items(&deployments_fmt)
-                .default(0)
-                .interact_opt()?
-                .expect("No deployment selected");
-
-            ensure!(
-                !deployments.is_empty(),
-                "No deployments found. Did you mean to `{EXEC_NAME} deploy`?"
-            );
-
-            deployments[idx].clone()
-        }
+    // the hopfile is what is being created, so do not fall back to it
+    let deployment = match options.deployment {
+        Some(ref query) => resolve_deployment(&state, Some(query), arg).await?,
+        None => pick_deployment(&state, arg).await?,
     };
 
     HopFile::new(dir.join("hop.yml"), &project.id, &deployment.id)
@@ -2,8 +2,10 @@ pub mod auth;
 mod channels;
 mod completions;
 pub mod containers;
+mod context;
 pub mod deploy;
 mod domains;
+mod events;
 mod gateways;
 pub mod ignite;
 mod link;
@@ -11,8 +13,10 @@ mod oops;
 mod payment;
 pub mod projects;
 mod secrets;
+mod top;
 mod tunnel;
 pub mod update;
+mod wait;
 mod whoami;
 
 use anyhow::Result;
@@ -23,6 +27,8 @@ use crate::state::State;
 #[derive(Debug, Subcommand)]
 pub enum Commands {
     Auth(auth::Options),
+    #[clap(alias = "contexts")]
+    Context(context::Options),
     #[clap(alias = "project")]
     Projects(projects::Options),
     #[clap(alias = "secret")]
@@ -56,11 +62,17 @@ pub enum Commands {
     Tunnel(tunnel::Options),
     #[clap(alias = "compose")]
     FromCompose(ignite::from_compose::Options),
+    #[clap(alias = "dashboard")]
+    Top(top::Options),
+    Wait(wait::Options),
+    #[clap(alias = "event")]
+    Events(events::Options),
 }
 
 pub async fn handle_command(command: Commands, mut state: State) -> Result<()> {
     match command {
         Commands::Auth(options) => auth::handle(options, state).await,
+        Commands::Context(options) => context::handle(options, state).await,
         #[cfg(feature = "update")]
         Commands::Update(options) => update::handle(options, state).await,
         Commands::Completions(options) => {
@@ -73,7 +85,7 @@ pub async fn handle_command(command: Commands, mut state: State) -> Result<()> {
             state.login(None).await?;
 
             match authorized_command {
-                Commands::Auth(_) | Commands::Completions(_) => {
+                Commands::Auth(_) | Commands::Context(_) | Commands::Completions(_) => {
                     unreachable!()
                 }
 
@@ -84,7 +96,7 @@ pub async fn handle_command(command: Commands, mut state: State) -> Result<()> {
                 Commands::Projects(options) => projects::handle(options, state).await,
                 Commands::Secrets(options) => secrets::handle(options, state).await,
                 Commands::Deploy(options) => deploy::handle(options, state).await,
-                Commands::Whoami(options) => whoami::handle(&options, state),
+                Commands::Whoami(options) => whoami::handle(&options, state).await,
                 Commands::Ignite(options) => ignite::handle(options, state).await,
                 Commands::Link(options) => link::handle(options, state).await,
                 Commands::Containers(options) => containers::handle(options, state).await,
@@ -96,6 +108,9 @@ pub async fn handle_command(command: Commands, mut state: State) -> Result<()> {
                     ignite::from_compose::handle(options, state).await
                 }
                 Commands::Payment(options) => payment::handle(options, state).await,
+                Commands::Top(options) => top::handle(options, state).await,
+                Commands::Wait(options) => wait::handle(options, state).await,
+                Commands::Events(options) => events::handle(options, state).await,
             }
         }
     }
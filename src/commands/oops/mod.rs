@@ -1,43 +1,28 @@
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, Result};
 use clap::Parser;
 
 use super::ignite::builds::types::BuildState;
 use super::ignite::builds::utils::get_all_builds;
-use super::ignite::utils::{format_deployments, get_all_deployments, promote};
-use crate::commands::projects::utils::format_project;
+use super::ignite::utils::promote;
+use crate::commands::ignite::utils::resolve_deployment;
 use crate::state::State;
-use crate::store::hopfile::HopFile;
+use crate::utils::prompt::Arg;
 
 #[derive(Debug, Parser)]
 #[clap(about = "Instantly roll back your deployment to a previous build")]
 pub struct Options {
-    #[clap(help = "ID of the deployment")]
+    #[clap(help = "ID or name of the deployment")]
     pub deployment: Option<String>,
 }
 
 pub async fn handle(options: &Options, state: State) -> Result<()> {
-    let deployment_id = if let Some(ref id) = options.deployment {
-        id.clone()
-    } else if let Some(hopfile) = HopFile::find_current().await {
-        hopfile.config.deployment_id
-    } else {
-        let project = state.ctx.current_project_error();
-
-        log::info!("Using project: {}", format_project(&project));
-
-        let deployments = get_all_deployments(&state.http, &project.id).await?;
-        ensure!(!deployments.is_empty(), "No deployments found.");
-        let deployments_fmt = format_deployments(&deployments, false);
-
-        let idx = dialoguer::Select::new()
-            .with_prompt("Select a deployment")
-            .items(&deployments_fmt)
-            .default(0)
-            .interact_opt()?
-            .ok_or_else(|| anyhow!("No deployment selected."))?;
-
-        deployments[idx].id.clone()
-    };
+    let deployment_id = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?
+    .id;
 
     let build_id = if let Some(build) = get_all_builds(&state.http, &deployment_id)
         .await?
@@ -9,6 +9,7 @@ use super::WEB_PAYMENTS_URL;
 use crate::commands::payment::utils::{format_payment_methods, get_all_payment_methods};
 use crate::state::http::HttpClient;
 use crate::utils::browser::listen_for_callback;
+use crate::utils::prompt::{self, Arg};
 use crate::utils::urlify;
 
 pub async fn get_payment_method_from_user(http: &HttpClient) -> Result<String> {
@@ -17,11 +18,12 @@ pub async fn get_payment_method_from_user(http: &HttpClient) -> Result<String> {
         let mut payment_methods_fmt = format_payment_methods(&payment_methods, false)?;
         payment_methods_fmt.push("New payment method".to_string());
 
-        let payment_method_idx = dialoguer::Select::new()
-            .with_prompt("Select a payment method")
-            .items(&payment_methods_fmt)
-            .default(0)
-            .interact()?;
+        let payment_method_idx = prompt::select(
+            "Select a payment method",
+            &payment_methods_fmt,
+            0,
+            Arg::new("payment method", "--payment-method"),
+        )?;
 
         if payment_method_idx == payment_methods_fmt.len() - 1 {
             let _ = Term::stderr().clear_last_lines(1);
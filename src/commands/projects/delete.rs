@@ -5,6 +5,7 @@ use serde_json::Value;
 use super::utils::format_projects;
 use crate::commands::projects::utils::format_project;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 static CONFIRM_DELETE_PROJECT_MESSAGE: &str = "I am sure I want to delete the project named ";
 
@@ -29,20 +30,19 @@ pub async fn handle(options: Options, mut state: State) -> Result<()> {
         None => {
             let projects_fmt = format_projects(&projects, false);
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a project")
-                .items(&projects_fmt)
-                .default(if let Some(current) = state.ctx.clone().current_project() {
+            let idx = prompt::select(
+                "Select a project",
+                &projects_fmt,
+                if let Some(current) = state.ctx.clone().current_project() {
                     projects
                         .iter()
                         .position(|p| p.id == current.id)
                         .unwrap_or(0)
                 } else {
                     0
-                })
-                .interact_opt()
-                .expect("Failed to select project")
-                .expect("No project selected");
+                },
+                Arg::new("project", "<PROJECT>"),
+            )?;
 
             projects[idx].clone()
         }
@@ -54,10 +54,8 @@ pub async fn handle(options: Options, mut state: State) -> Result<()> {
             CONFIRM_DELETE_PROJECT_MESSAGE, project.name
         );
 
-        let output = dialoguer::Input::<String>::new()
-            .with_prompt("Message")
-            .interact_text()
-            .expect("Failed to confirm deletion");
+        let output = prompt::input::<String>("Message", Arg::new("confirmation", "--force"))?
+            .interact_text()?;
 
         assert!(
             output == CONFIRM_DELETE_PROJECT_MESSAGE.to_string() + &project.name,
@@ -1,14 +1,61 @@
+use std::io::Write;
+
+use anyhow::Result;
 use clap::Parser;
+use serde_json::json;
+use tabwriter::TabWriter;
 
-use crate::commands::projects::utils::format_project;
+use crate::commands::projects::members::utils::get_all_members;
 use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
 
-#[derive(Debug, Parser)]
+#[derive(Debug, Parser, Default)]
 #[clap(about = "Get information about a project")]
-pub struct Options {}
+pub struct Options {
+    #[clap(
+        short,
+        long,
+        help = "How to print the project",
+        value_enum,
+        default_value = "text"
+    )]
+    pub output: OutputFormat,
+}
+
+pub async fn handle(options: &Options, state: State) -> Result<()> {
+    let project = state.ctx.clone().current_project_error()?;
+
+    // project tokens might not be allowed to see the members
+    let member_count = match get_all_members(&state.http, &project.id).await {
+        Ok(members) => Some(members.len()),
+        Err(error) => {
+            log::debug!("Failed to get members: {error}");
+            None
+        }
+    };
+
+    if options.output == OutputFormat::Json {
+        let mut value = serde_json::to_value(&project)?;
+        value["member_count"] = json!(member_count);
+
+        return print_json(&value);
+    }
+
+    let mut tw = TabWriter::new(vec![]);
+
+    writeln!(tw, "Name\t{}", project.name)?;
+    writeln!(tw, "Namespace\t/{}", project.namespace)?;
+    writeln!(tw, "ID\t{}", project.id)?;
+    writeln!(tw, "Type\t{}", project.type_)?;
+    writeln!(tw, "Created\t{}", project.created_at)?;
+    writeln!(
+        tw,
+        "Members\t{}",
+        member_count.map_or_else(|| "-".to_string(), |count| count.to_string())
+    )?;
 
-pub fn handle(_options: &Options, state: State) {
-    let project = state.ctx.current_project_error();
+    log::info!("Project: `{}`", project.name);
+    print!("{}", String::from_utf8(tw.into_inner()?)?);
 
-    log::info!("Project: {}", format_project(&project));
+    Ok(())
 }
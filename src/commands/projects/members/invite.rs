@@ -0,0 +1,63 @@
+use anyhow::{ensure, Result};
+use clap::Parser;
+
+use super::types::MemberRole;
+use super::utils::{ask_role, invite_member, validate_role};
+use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Invite someone to a project")]
+pub struct Options {
+    #[clap(help = "Email of the person to invite")]
+    email: Option<String>,
+
+    #[clap(short, long, help = "Role of the new member", value_enum)]
+    role: Option<MemberRole>,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the result",
+        value_enum,
+        default_value = "text"
+    )]
+    output: OutputFormat,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project = state.ctx.clone().current_project_error()?;
+
+    let email = match options.email {
+        Some(email) => email,
+        None => prompt::input::<String>(
+            "Email of the person to invite",
+            Arg::new("email", "<EMAIL>"),
+        )?
+        .interact_text()?,
+    };
+
+    ensure!(email.contains('@'), "`{email}` is not a valid email");
+
+    let role = match options.role {
+        Some(role) => role,
+        None => ask_role("Select a role", Arg::new("role", "--role"))?,
+    };
+
+    validate_role(role)?;
+
+    invite_member(&state.http, &project.id, &email, role).await?;
+
+    if options.output == OutputFormat::Json {
+        return print_json(&serde_json::json!({
+            "project": project.id,
+            "email": email,
+            "role": role,
+        }));
+    }
+
+    log::info!("Invited `{email}` to `{}` as {role}", project.namespace);
+
+    Ok(())
+}
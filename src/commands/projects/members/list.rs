@@ -0,0 +1,47 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{format_members, get_all_members};
+use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
+
+#[derive(Debug, Parser)]
+#[clap(about = "List all members of a project")]
+pub struct Options {
+    #[clap(short, long, help = "Only print the IDs of the members")]
+    quiet: bool,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the members",
+        value_enum,
+        default_value = "text"
+    )]
+    output: OutputFormat,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.current_project_error()?.id;
+    let members = get_all_members(&state.http, &project_id).await?;
+
+    if options.output == OutputFormat::Json {
+        return print_json(&members);
+    }
+
+    if options.quiet {
+        let ids = members
+            .iter()
+            .map(|m| m.id.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        println!("{ids}");
+    } else {
+        let members_fmt = format_members(&members, true);
+
+        println!("{}", members_fmt.join("\n"));
+    }
+
+    Ok(())
+}
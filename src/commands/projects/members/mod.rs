@@ -0,0 +1,39 @@
+mod invite;
+mod list;
+mod remove;
+mod set_role;
+pub mod types;
+pub mod utils;
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+pub enum Commands {
+    #[clap(name = "ls", alias = "list")]
+    List(list::Options),
+    #[clap(alias = "add")]
+    Invite(invite::Options),
+    #[clap(name = "set-role")]
+    SetRole(set_role::Options),
+    #[clap(name = "rm", alias = "remove")]
+    Remove(remove::Options),
+}
+
+#[derive(Debug, Parser)]
+#[clap(about = "Manage the members of a project")]
+pub struct Options {
+    #[clap(subcommand)]
+    pub commands: Commands,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    match options.commands {
+        Commands::List(options) => list::handle(options, state).await,
+        Commands::Invite(options) => invite::handle(options, state).await,
+        Commands::SetRole(options) => set_role::handle(options, state).await,
+        Commands::Remove(options) => remove::handle(options, state).await,
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+
+use super::types::MemberRole;
+use super::utils::{get_member, remove_member};
+use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
+use crate::utils::prompt;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Remove a member from a project")]
+pub struct Options {
+    #[clap(help = "ID, email or username of the member")]
+    member: Option<String>,
+
+    #[clap(short, long, help = "Skip confirmation")]
+    force: bool,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the removed member",
+        value_enum,
+        default_value = "text"
+    )]
+    output: OutputFormat,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project = state.ctx.clone().current_project_error()?;
+
+    let member = get_member(&state.http, &project.id, options.member).await?;
+
+    if member.role == MemberRole::Owner {
+        bail!("The project owner can not be removed");
+    }
+
+    if !options.force
+        && !prompt::confirm(
+            format!(
+                "Are you sure you want to remove `{}` ({}) from `{}`?",
+                member.user.name, member.user.email, project.namespace
+            ),
+            false,
+            "--force",
+        )?
+    {
+        bail!("Aborted");
+    }
+
+    remove_member(&state.http, &project.id, &member.id).await?;
+
+    if options.output == OutputFormat::Json {
+        return print_json(&member);
+    }
+
+    log::info!(
+        "Removed `{}` from `{}`",
+        member.user.name,
+        project.namespace
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+
+use super::types::MemberRole;
+use super::utils::{ask_role, get_member, update_member_role, validate_role};
+use crate::state::State;
+use crate::utils::output::{print_json, OutputFormat};
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Change the role of a member")]
+pub struct Options {
+    #[clap(help = "ID, email or username of the member")]
+    member: Option<String>,
+
+    #[clap(help = "New role of the member", value_enum)]
+    role: Option<MemberRole>,
+
+    #[clap(short, long, help = "Skip confirmation")]
+    force: bool,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the updated member",
+        value_enum,
+        default_value = "text"
+    )]
+    output: OutputFormat,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.clone().current_project_error()?.id;
+
+    let member = get_member(&state.http, &project_id, options.member).await?;
+
+    let role = match options.role {
+        Some(role) => role,
+        None => ask_role(
+            &format!("Select a new role for `{}`", member.user.name),
+            Arg::new("role", "<ROLE>"),
+        )?,
+    };
+
+    validate_role(role)?;
+
+    if member.role == MemberRole::Owner {
+        bail!("The role of the project owner can not be changed");
+    }
+
+    if member.role == role {
+        log::info!("`{}` already is {role}", member.user.name);
+
+        return Ok(());
+    }
+
+    if !options.force
+        && !prompt::confirm(
+            format!(
+                "Change the role of `{}` from {} to {role}?",
+                member.user.name, member.role
+            ),
+            false,
+            "--force",
+        )?
+    {
+        bail!("Aborted");
+    }
+
+    let updated = update_member_role(&state.http, &project_id, &member.id, role).await?;
+
+    if options.output == OutputFormat::Json {
+        return print_json(&updated);
+    }
+
+    log::info!("`{}` is now {}", updated.user.name, updated.role);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum MemberRole {
+    Owner,
+    Admin,
+    Editor,
+    Viewer,
+}
+
+impl MemberRole {
+    /// Roles that can be given out, a project only has one owner.
+    pub fn assignable() -> Vec<Self> {
+        vec![Self::Admin, Self::Editor, Self::Viewer]
+    }
+}
+
+impl Display for MemberRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Owner => write!(f, "owner"),
+            Self::Admin => write!(f, "admin"),
+            Self::Editor => write!(f, "editor"),
+            Self::Viewer => write!(f, "viewer"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemberUser {
+    pub id: String,
+    pub name: String,
+    pub username: String,
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Member {
+    pub id: String,
+    pub user: MemberUser,
+    pub role: MemberRole,
+    pub joined_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultipleMembers {
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SingleMember {
+    pub member: Member,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InviteMember {
+    pub email: String,
+    pub role: MemberRole,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateMember {
+    pub role: MemberRole,
+}
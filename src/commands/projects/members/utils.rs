@@ -0,0 +1,145 @@
+use std::io::Write;
+
+use anyhow::{anyhow, ensure, Result};
+use serde_json::Value;
+use tabwriter::TabWriter;
+
+use super::types::{InviteMember, Member, MemberRole, MultipleMembers, SingleMember, UpdateMember};
+use crate::error::HopError;
+use crate::state::http::HttpClient;
+use crate::utils::prompt::{self, Arg};
+
+pub async fn get_all_members(http: &HttpClient, project_id: &str) -> Result<Vec<Member>> {
+    let response = http
+        .request::<MultipleMembers>("GET", &format!("/projects/{project_id}/members"), None)
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.members)
+}
+
+pub async fn invite_member(
+    http: &HttpClient,
+    project_id: &str,
+    email: &str,
+    role: MemberRole,
+) -> Result<()> {
+    let data = serde_json::to_vec(&InviteMember {
+        email: email.to_string(),
+        role,
+    })?;
+
+    http.request::<Value>(
+        "POST",
+        &format!("/projects/{project_id}/invites"),
+        Some((data.into(), "application/json")),
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn update_member_role(
+    http: &HttpClient,
+    project_id: &str,
+    member_id: &str,
+    role: MemberRole,
+) -> Result<Member> {
+    let data = serde_json::to_vec(&UpdateMember { role })?;
+
+    let response = http
+        .request::<SingleMember>(
+            "PATCH",
+            &format!("/projects/{project_id}/members/{member_id}"),
+            Some((data.into(), "application/json")),
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.member)
+}
+
+pub async fn remove_member(http: &HttpClient, project_id: &str, member_id: &str) -> Result<()> {
+    http.request::<Value>(
+        "DELETE",
+        &format!("/projects/{project_id}/members/{member_id}"),
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Finds a member by ID, email or username, or asks to pick one.
+pub async fn get_member(
+    http: &HttpClient,
+    project_id: &str,
+    member: Option<String>,
+) -> Result<Member> {
+    let members = get_all_members(http, project_id).await?;
+
+    match member {
+        Some(member) => members
+            .into_iter()
+            .find(|m| {
+                m.id == member
+                    || m.user.email.eq_ignore_ascii_case(&member)
+                    || m.user.username.eq_ignore_ascii_case(&member)
+            })
+            .ok_or_else(|| HopError::NotFound(format!("Member `{member}` not found")).into()),
+
+        None => {
+            ensure!(!members.is_empty(), "No members found");
+            let members_fmt = format_members(&members, false);
+
+            let idx = prompt::select(
+                "Select a member",
+                &members_fmt,
+                0,
+                Arg::new("member", "<MEMBER>"),
+            )?;
+
+            Ok(members[idx].clone())
+        }
+    }
+}
+
+pub fn ask_role(prompt: &str, arg: Arg) -> Result<MemberRole> {
+    let roles = MemberRole::assignable();
+
+    let idx = prompt::select(prompt, &roles, roles.len() - 1, arg)?;
+
+    Ok(roles[idx])
+}
+
+pub fn validate_role(role: MemberRole) -> Result<()> {
+    ensure!(
+        role != MemberRole::Owner,
+        HopError::Validation("The owner role can not be given to members".to_string())
+    );
+
+    Ok(())
+}
+
+pub fn format_members(members: &[Member], title: bool) -> Vec<String> {
+    let mut tw = TabWriter::new(vec![]);
+
+    if title {
+        writeln!(tw, "NAME\tEMAIL\tROLE\tJOINED\tID").unwrap();
+    }
+
+    for member in members {
+        writeln!(
+            tw,
+            "{}\t{}\t{}\t{}\t{}",
+            member.user.name, member.user.email, member.role, member.joined_at, member.id,
+        )
+        .unwrap();
+    }
+
+    String::from_utf8(tw.into_inner().unwrap())
+        .unwrap()
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect()
+}
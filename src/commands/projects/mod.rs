@@ -3,7 +3,9 @@ mod delete;
 pub mod finance;
 pub mod info;
 mod list;
+mod members;
 mod switch;
+mod tokens;
 pub mod types;
 pub mod utils;
 
@@ -21,6 +23,10 @@ pub enum Commands {
     List(list::Options),
     #[clap(name = "rm", alias = "delete")]
     Delete(delete::Options),
+    #[clap(alias = "token")]
+    Tokens(tokens::Options),
+    #[clap(alias = "member")]
+    Members(members::Options),
 }
 
 #[derive(Debug, Parser)]
@@ -41,9 +47,8 @@ pub async fn handle(options: Options, state: State) -> anyhow::Result<()> {
             Ok(())
         }
 
-        Commands::Info(options) => {
-            info::handle(&options, state);
-            Ok(())
-        }
+        Commands::Info(options) => info::handle(&options, state).await,
+        Commands::Tokens(options) => tokens::handle(options, state).await,
+        Commands::Members(options) => members::handle(options, state).await,
     }
 }
@@ -3,6 +3,7 @@ use clap::Parser;
 
 use crate::commands::projects::utils::{format_project, format_projects};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Switch to a different project")]
@@ -22,20 +23,19 @@ pub async fn handle(options: Options, mut state: State) -> Result<()> {
         None => {
             let projects_fmt = format_projects(&projects, false);
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a project")
-                .items(&projects_fmt)
-                .default(if let Some(current) = state.ctx.clone().current_project() {
+            let idx = prompt::select(
+                "Select a project",
+                &projects_fmt,
+                if let Some(current) = state.ctx.clone().current_project() {
                     projects
                         .iter()
                         .position(|p| p.id == current.id)
                         .unwrap_or(0)
                 } else {
                     0
-                })
-                .interact_opt()
-                .expect("Failed to select project")
-                .expect("No project selected");
+                },
+                Arg::new("project", "<PROJECT>"),
+            )?;
 
             &projects[idx]
         }
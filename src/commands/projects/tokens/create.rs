@@ -0,0 +1,103 @@
+use anyhow::{anyhow, ensure, Result};
+use clap::Parser;
+
+use super::utils::{create_project_token, format_project_tokens, get_all_project_tokens};
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::output::{print_token, TokenOutput};
+use crate::utils::parse_expiration;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Create a new project token")]
+pub struct Options {
+    #[clap(
+        long,
+        help = "Permission flags of the token, as defined by the Hop API",
+        conflicts_with = "like"
+    )]
+    flags: Option<u64>,
+
+    #[clap(long, help = "ID of an existing token to copy the permissions of")]
+    like: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Expiration date of the token, can be a date or a duration (60s, 1d, 30d, 1y)",
+        value_parser = parse_expiration
+    )]
+    expiration: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "How to print the token",
+        value_enum,
+        default_value = "text"
+    )]
+    output: TokenOutput,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project = state.ctx.clone().current_project_error()?;
+
+    let flags = match options.flags {
+        Some(flags) => flags,
+
+        None => {
+            let tokens = get_all_project_tokens(&state.http, &project.id).await?;
+
+            match options.like {
+                Some(id) => {
+                    tokens
+                        .iter()
+                        .find(|token| token.id == id)
+                        .ok_or_else(|| {
+                            HopError::NotFound(format!("No project token with the ID `{id}`"))
+                        })?
+                        .flags
+                }
+
+                None => {
+                    ensure!(
+                        !tokens.is_empty(),
+                        HopError::Usage(
+                            "The project has no tokens to copy the permissions of, pass `--flags`"
+                                .to_string()
+                        )
+                    );
+
+                    let idx = prompt::select(
+                        "Copy the permissions of",
+                        &format_project_tokens(&tokens, false),
+                        0,
+                        Arg::new("flags", "--flags or --like"),
+                    )?;
+
+                    tokens[idx].flags
+                }
+            }
+        }
+    };
+
+    ensure!(flags != 0, "A project token needs at least one permission");
+
+    let created = create_project_token(&state.http, &project.id, flags, options.expiration).await?;
+
+    let token = created
+        .token
+        .clone()
+        .ok_or_else(|| anyhow!("The token was created but not returned"))?;
+
+    // info logs go to stdout, keep it clean for `--output env` and `--output json`
+    if options.output == TokenOutput::Text {
+        log::info!(
+            "Created project token `{}` for `{}`",
+            created.id,
+            project.namespace
+        );
+    }
+
+    print_token(options.output, &token, &created)
+}
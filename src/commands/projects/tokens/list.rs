@@ -0,0 +1,33 @@
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{format_project_tokens, get_all_project_tokens};
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "List all project tokens")]
+pub struct Options {
+    #[clap(short, long, help = "Only print the IDs of the tokens")]
+    quiet: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.current_project_error()?.id;
+    let tokens = get_all_project_tokens(&state.http, &project_id).await?;
+
+    if options.quiet {
+        let ids = tokens
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        println!("{ids}");
+    } else {
+        let tokens_fmt = format_project_tokens(&tokens, true);
+
+        println!("{}", tokens_fmt.join("\n"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+mod create;
+mod list;
+mod revoke;
+mod types;
+mod utils;
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+pub enum Commands {
+    #[clap(name = "new", alias = "create")]
+    Create(create::Options),
+    #[clap(name = "ls", alias = "list")]
+    List(list::Options),
+    #[clap(name = "revoke", alias = "rm", alias = "delete")]
+    Revoke(revoke::Options),
+}
+
+#[derive(Debug, Parser)]
+#[clap(about = "Manage project tokens")]
+pub struct Options {
+    #[clap(subcommand)]
+    pub commands: Commands,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    match options.commands {
+        Commands::Create(options) => create::handle(options, state).await,
+        Commands::List(options) => list::handle(options, state).await,
+        Commands::Revoke(options) => revoke::handle(options, state).await,
+    }
+}
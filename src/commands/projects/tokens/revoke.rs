@@ -0,0 +1,62 @@
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+
+use super::utils::{format_project_tokens, get_all_project_tokens, revoke_project_token};
+use crate::state::State;
+use crate::utils::prompt::{self, Arg};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Revoke project tokens")]
+pub struct Options {
+    #[clap(help = "IDs of the project tokens")]
+    tokens: Vec<String>,
+
+    #[clap(short, long, help = "Skip confirmation")]
+    force: bool,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.current_project_error()?.id;
+
+    let tokens = if !options.tokens.is_empty() {
+        options.tokens
+    } else {
+        let tokens = get_all_project_tokens(&state.http, &project_id).await?;
+        ensure!(!tokens.is_empty(), "No project tokens found");
+        let tokens_fmt = format_project_tokens(&tokens, false);
+
+        let idxs = prompt::multi_select(
+            "Select the project tokens to revoke",
+            &tokens_fmt,
+            Arg::new("tokens", "<TOKENS>"),
+        )?;
+
+        tokens
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| idxs.contains(i))
+            .map(|(_, t)| t.id.clone())
+            .collect()
+    };
+
+    if !options.force
+        && !prompt::confirm(format!(
+                "Are you sure you want to revoke {} project tokens? Anything using them will lose access",
+                tokens.len()
+            ), false, "--force")?
+    {
+        bail!("Aborted");
+    }
+
+    let mut revoke_count = 0;
+
+    for token in &tokens {
+        log::info!("Revoking project token `{token}`");
+        revoke_project_token(&state.http, &project_id, token).await?;
+        revoke_count += 1;
+    }
+
+    log::info!("Revoked {revoke_count}/{} project tokens", tokens.len());
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProjectToken {
+    pub id: String,
+    /// only sent in full when the token is created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// permission bit flags, their meaning is defined by the Hop API so
+    /// the CLI passes them through as is instead of keeping its own copy
+    pub flags: u64,
+    pub created_at: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateProjectToken {
+    pub flags: u64,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SingleProjectToken {
+    pub project_token: ProjectToken,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultipleProjectTokens {
+    pub project_tokens: Vec<ProjectToken>,
+}
@@ -0,0 +1,76 @@
+use std::io::Write;
+
+use anyhow::{anyhow, Result};
+use tabwriter::TabWriter;
+
+use super::types::{CreateProjectToken, MultipleProjectTokens, ProjectToken, SingleProjectToken};
+use crate::state::http::HttpClient;
+
+pub async fn create_project_token(
+    http: &HttpClient,
+    project_id: &str,
+    flags: u64,
+    expires_at: Option<String>,
+) -> Result<ProjectToken> {
+    let data = serde_json::to_vec(&CreateProjectToken { flags, expires_at })?;
+
+    let response = http
+        .request::<SingleProjectToken>(
+            "POST",
+            &format!("/projects/{project_id}/tokens"),
+            Some((data.into(), "application/json")),
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.project_token)
+}
+
+pub async fn get_all_project_tokens(
+    http: &HttpClient,
+    project_id: &str,
+) -> Result<Vec<ProjectToken>> {
+    let response = http
+        .request::<MultipleProjectTokens>("GET", &format!("/projects/{project_id}/tokens"), None)
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.project_tokens)
+}
+
+pub async fn revoke_project_token(http: &HttpClient, project_id: &str, token: &str) -> Result<()> {
+    http.request::<()>(
+        "DELETE",
+        &format!("/projects/{project_id}/tokens/{token}"),
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub fn format_project_tokens(tokens: &[ProjectToken], title: bool) -> Vec<String> {
+    let mut tw = TabWriter::new(vec![]);
+
+    if title {
+        writeln!(tw, "ID\tFLAGS\tCREATION\tEXPIRATION").unwrap();
+    }
+
+    for token in tokens {
+        writeln!(
+            tw,
+            "{}\t{}\t{}\t{}",
+            token.id,
+            token.flags,
+            token.created_at,
+            token.expires_at.as_deref().unwrap_or("-"),
+        )
+        .unwrap();
+    }
+
+    String::from_utf8(tw.into_inner().unwrap())
+        .unwrap()
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect()
+}
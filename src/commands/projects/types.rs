@@ -11,7 +11,7 @@ pub struct SingleProjectResponse {
     pub project: Project,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Project {
     pub id: String,
     pub name: String,
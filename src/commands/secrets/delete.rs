@@ -2,9 +2,10 @@ use anyhow::{bail, Result};
 use clap::Parser;
 use serde_json::Value;
 
-use crate::commands::secrets::types::Secrets;
-use crate::commands::secrets::utils::validate_name;
+use crate::commands::secrets::utils::{get_all_secrets, get_secret_references, validate_name};
+use crate::error::HopError;
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Delete a secret")]
@@ -13,51 +14,70 @@ pub struct Options {
     name: Option<String>,
     #[clap(short, long, help = "Skip confirmation")]
     force: bool,
+    #[clap(long, help = "Delete the secret even if deployments use it")]
+    even_if_used: bool,
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
     if let Some(ref name) = options.name {
-        validate_name(name).unwrap();
+        validate_name(name)?;
     }
 
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
     let secret_name = match options.name {
         Some(name) => name,
         None => {
-            let secrets = state
-                .http
-                .request::<Secrets>("GET", &format!("/projects/{project_id}/secrets"), None)
-                .await?
-                .unwrap()
-                .secrets;
+            let secrets = get_all_secrets(&state.http, &project_id).await?;
 
-            assert!(!secrets.is_empty(), "No secrets found");
+            if secrets.is_empty() {
+                return Err(HopError::NotFound("No secrets found".to_string()).into());
+            }
 
             let secrets_fmt = secrets
                 .iter()
                 .map(|s| format!(" {} ({})", s.name, s.id))
                 .collect::<Vec<_>>();
 
-            let idx = dialoguer::Select::new()
-                .with_prompt("Select a secret")
-                .items(&secrets_fmt)
-                .default(0)
-                .interact_opt()
-                .expect("Failed to select secret")
-                .expect("No secret selected");
+            let idx = prompt::select(
+                "Select a secret",
+                &secrets_fmt,
+                0,
+                Arg::new("name", "<NAME>"),
+            )?;
 
             secrets[idx].name.clone()
         }
     };
 
-    if !options.force
-        && !dialoguer::Confirm::new()
-            .with_prompt(format!(
-                "Are you sure you want to delete secret `{secret_name}`?"
+    let used_by = get_secret_references(&state.http, &project_id)
+        .await?
+        .into_iter()
+        .filter(|reference| reference.secret == secret_name.to_uppercase())
+        .map(|reference| format!("{} ({})", reference.deployment, reference.variable))
+        .collect::<Vec<_>>();
+
+    if !used_by.is_empty() {
+        if !options.even_if_used {
+            return Err(HopError::Conflict(format!(
+                "Secret `{secret_name}` is used by {}, deleting it breaks their next rollout. Pass `--even-if-used` to delete it anyway",
+                used_by.join(", ")
             ))
-            .interact_opt()?
-            .unwrap_or(false)
+            .into());
+        }
+
+        log::warn!(
+            "Secret `{secret_name}` is still used by {}",
+            used_by.join(", ")
+        );
+    }
+
+    if !options.force
+        && !prompt::confirm(
+            format!("Are you sure you want to delete secret `{secret_name}`?"),
+            false,
+            "--force",
+        )?
     {
         bail!("Aborted");
     }
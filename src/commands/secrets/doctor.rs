@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use std::io::Write;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use tabwriter::TabWriter;
+
+use crate::commands::secrets::utils::{get_all_secrets, get_secret_references};
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Find deployments using secrets that do not exist")]
+pub struct Options {}
+
+pub async fn handle(_options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.current_project_error()?.id;
+
+    let (secrets, references) = tokio::try_join!(
+        get_all_secrets(&state.http, &project_id),
+        get_secret_references(&state.http, &project_id),
+    )?;
+
+    let existing = secrets
+        .iter()
+        .map(|secret| secret.name.to_uppercase())
+        .collect::<HashSet<_>>();
+
+    let dangling = references
+        .iter()
+        .filter(|reference| !existing.contains(&reference.secret))
+        .collect::<Vec<_>>();
+
+    if dangling.is_empty() {
+        log::info!("All secrets used by deployments exist");
+
+        return Ok(());
+    }
+
+    let mut tw = TabWriter::new(vec![]);
+
+    writeln!(&mut tw, "DEPLOYMENT\tVARIABLE\tMISSING SECRET")?;
+
+    for reference in &dangling {
+        writeln!(
+            &mut tw,
+            "{}\t{}\t{}",
+            reference.deployment, reference.variable, reference.secret
+        )?;
+    }
+
+    print!("{}", String::from_utf8(tw.into_inner()?)?);
+
+    bail!("Deployments use secrets that do not exist, create them with `hop secrets set`");
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::commands::secrets::utils::get_all_secrets;
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Print a .env template with the names of all secrets and empty values")]
+pub struct Options {}
+
+pub async fn handle(_options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.current_project_error()?.id;
+
+    let mut names = get_all_secrets(&state.http, &project_id)
+        .await?
+        .into_iter()
+        .map(|secret| secret.name)
+        .collect::<Vec<_>>();
+    names.sort();
+
+    for name in names {
+        println!("{name}=");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use futures_util::{stream, StreamExt};
+
+use crate::commands::ignite::utils::env_file_to_map;
+use crate::commands::secrets::utils::{set_secret, validate_name};
+use crate::state::State;
+
+const IMPORT_CONCURRENCY: usize = 5;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Set secrets from a .env file")]
+pub struct Options {
+    #[clap(help = "Path to the file", default_value = ".env")]
+    pub file: PathBuf,
+
+    #[clap(long, help = "Prefix to add to the name of every secret, e.g. `PROD_`")]
+    pub prefix: Option<String>,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let project_id = state.ctx.clone().current_project_error()?.id;
+
+    let mut secrets = env_file_to_map(options.file)
+        .await?
+        .into_iter()
+        .map(|(name, value)| {
+            (
+                format!("{}{name}", options.prefix.as_deref().unwrap_or_default()).to_uppercase(),
+                value,
+            )
+        })
+        .collect::<Vec<_>>();
+    secrets.sort();
+
+    // check every name before setting any of them
+    let invalid = secrets
+        .iter()
+        .filter(|(name, _)| validate_name(name).is_err())
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+
+    if !invalid.is_empty() {
+        bail!(
+            "Invalid secret names: {}. Names are limited to 64 characters and must be alphanumeric (with underscores)",
+            invalid.join(", ")
+        );
+    }
+
+    if secrets.is_empty() {
+        log::info!("No secrets to import");
+
+        return Ok(());
+    }
+
+    let http = &state.http;
+    let project_id = &project_id;
+
+    let results = stream::iter(secrets)
+        .map(|(name, value)| async move {
+            let result = set_secret(http, project_id, &name, value).await;
+
+            (name, result)
+        })
+        .buffer_unordered(IMPORT_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut failed = 0;
+
+    for (name, result) in &results {
+        match result {
+            Ok(secret) => log::info!("Set secret: {} ({})", secret.name, secret.id),
+            Err(error) => {
+                log::error!("Failed to set secret `{name}`: {error}");
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("Failed to set {failed} of {} secrets", results.len());
+    }
+
+    Ok(())
+}
@@ -1,8 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 
-use crate::commands::secrets::types::Secrets;
-use crate::commands::secrets::utils::format_secrets;
+use crate::commands::secrets::utils::{format_secrets, get_all_secrets, get_secret_references};
 use crate::state::State;
 
 #[derive(Debug, Parser)]
@@ -10,17 +9,15 @@ use crate::state::State;
 pub struct Options {
     #[clap(short, long, help = "Only print the IDs of the secrets")]
     pub quiet: bool,
+
+    #[clap(short, long, help = "Show which deployments use each secret")]
+    pub usage: bool,
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
-    let project_id = state.ctx.current_project_error().id;
+    let project_id = state.ctx.current_project_error()?.id;
 
-    let secrets = state
-        .http
-        .request::<Secrets>("GET", &format!("/projects/{project_id}/secrets"), None)
-        .await?
-        .unwrap()
-        .secrets;
+    let secrets = get_all_secrets(&state.http, &project_id).await?;
 
     if options.quiet {
         let ids = secrets
@@ -31,7 +28,13 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
 
         println!("{ids}");
     } else {
-        let secrets_fmt = format_secrets(&secrets, true);
+        let usage = if options.usage {
+            Some(get_secret_references(&state.http, &project_id).await?)
+        } else {
+            None
+        };
+
+        let secrets_fmt = format_secrets(&secrets, usage.as_deref(), true);
 
         println!("{}", secrets_fmt.join("\n"));
     }
@@ -1,4 +1,7 @@
 mod delete;
+mod doctor;
+mod export_names;
+mod import;
 mod list;
 mod set;
 mod types;
@@ -17,6 +20,10 @@ pub enum Commands {
     List(list::Options),
     #[clap(name = "rm", alias = "del", alias = "delete", alias = "remove")]
     Delete(delete::Options),
+    Import(import::Options),
+    #[clap(name = "export-names")]
+    ExportNames(export_names::Options),
+    Doctor(doctor::Options),
 }
 
 #[derive(Debug, Parser)]
@@ -31,5 +38,8 @@ pub async fn handle(options: Options, state: State) -> Result<()> {
         Commands::List(options) => list::handle(options, state).await,
         Commands::Set(options) => set::handle(options, state).await,
         Commands::Delete(options) => delete::handle(options, state).await,
+        Commands::Import(options) => import::handle(options, state).await,
+        Commands::ExportNames(options) => export_names::handle(options, state).await,
+        Commands::Doctor(options) => doctor::handle(options, state).await,
     }
 }
@@ -1,39 +1,86 @@
-use anyhow::{anyhow, Result};
+use std::path::PathBuf;
+
+use anyhow::{ensure, Context, Result};
 use clap::Parser;
+use tokio::fs;
+use tokio::io::{stdin, AsyncReadExt};
 
-use crate::commands::secrets::types::SecretResponse;
-use crate::commands::secrets::utils::validate_name;
+use crate::commands::secrets::utils::{set_secret, validate_name};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
 
 #[derive(Debug, Parser)]
-#[clap(about = "Set a secret")]
+#[clap(about = "Set a secret, the value is prompted for unless passed")]
 pub struct Options {
     #[clap(help = "Name of the secret")]
     name: String,
-    #[clap(help = "Value of the secret")]
-    value: String,
+
+    #[clap(
+        help = "Value of the secret, prefer the other ways to pass it so it stays out of your shell history",
+        conflicts_with_all = &["from-file", "stdin"]
+    )]
+    value: Option<String>,
+
+    #[clap(long, help = "Read the value from a file", conflicts_with = "stdin")]
+    from_file: Option<PathBuf>,
+
+    #[clap(long, help = "Read the value from stdin")]
+    stdin: bool,
 }
 
 pub async fn handle(options: Options, state: State) -> Result<()> {
     validate_name(&options.name)?;
 
-    let project_id = state.ctx.current_project().expect("Project not found").id;
-
-    let secret = state
-        .http
-        .request::<SecretResponse>(
-            "PUT",
-            &format!(
-                "/projects/{project_id}/secrets/{}",
-                options.name.to_uppercase()
-            ),
-            Some((options.value.into(), "text/plain")),
-        )
-        .await?
-        .ok_or_else(|| anyhow!("Error while parsing response"))?
-        .secret;
+    let project_id = state.ctx.clone().current_project_error()?.id;
+
+    let value = if let Some(value) = options.value {
+        value
+    } else if let Some(path) = options.from_file {
+        let value = fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        trim_newline(value)
+    } else if options.stdin {
+        let mut value = String::new();
+        stdin().read_to_string(&mut value).await?;
+
+        trim_newline(value)
+    } else {
+        prompt::password(
+            format!("Value of secret `{}`", options.name.to_uppercase()),
+            Arg::new("value", "--stdin or --from-file"),
+        )?
+        .interact()?
+    };
+
+    ensure!(!value.is_empty(), "The value of a secret can not be empty");
+
+    let secret = set_secret(&state.http, &project_id, &options.name, value).await?;
 
     log::info!("Set secret: {} ({})", secret.name, secret.id);
 
     Ok(())
 }
+
+/// `echo` and most editors end with a newline that is not part of the value.
+fn trim_newline(value: String) -> String {
+    value
+        .strip_suffix('\n')
+        .map(|v| v.strip_suffix('\r').unwrap_or(v).to_string())
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_newline() {
+        assert_eq!(trim_newline("value\n".to_string()), "value");
+        assert_eq!(trim_newline("value\r\n".to_string()), "value");
+        assert_eq!(trim_newline("value\n\n".to_string()), "value\n");
+        assert_eq!(trim_newline("line 1\nline 2".to_string()), "line 1\nline 2");
+        assert_eq!(trim_newline("value".to_string()), "value");
+    }
+}
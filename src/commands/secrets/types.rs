@@ -17,3 +17,11 @@ pub struct Secrets {
 pub struct SecretResponse {
     pub secret: Secret,
 }
+
+/// An environment variable of a deployment set to `${secrets.NAME}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretReference {
+    pub secret: String,
+    pub deployment: String,
+    pub variable: String,
+}
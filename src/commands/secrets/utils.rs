@@ -1,35 +1,91 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::Write;
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, Result};
 use regex::Regex;
 use tabwriter::TabWriter;
 
-use super::types::Secret;
+use super::types::{Secret, SecretReference, SecretResponse, Secrets};
+use crate::commands::ignite::types::Deployment;
+use crate::commands::ignite::utils::get_all_deployments;
+use crate::error::HopError;
+use crate::state::http::HttpClient;
+
+pub async fn get_all_secrets(http: &HttpClient, project_id: &str) -> Result<Vec<Secret>> {
+    let response = http
+        .request::<Secrets>("GET", &format!("/projects/{project_id}/secrets"), None)
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.secrets)
+}
+
+/// Creates or updates a secret, names are uppercased.
+pub async fn set_secret(
+    http: &HttpClient,
+    project_id: &str,
+    name: &str,
+    value: String,
+) -> Result<Secret> {
+    let response = http
+        .request::<SecretResponse>(
+            "PUT",
+            &format!("/projects/{project_id}/secrets/{}", name.to_uppercase()),
+            Some((value.into(), "text/plain")),
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Error while parsing response"))?;
+
+    Ok(response.secret)
+}
 
 pub fn validate_name(name: &str) -> Result<()> {
     let regex = regex::Regex::new(r"(?i)^[a-z0-9_]{1,64}$").unwrap();
 
     if !regex.is_match(name) {
-        bail!("Invalid name. Secret names are limited to 64 characters in length, must be alphanumeric (with underscores) and are automatically uppercased.")
+        return Err(HopError::Validation("Invalid name. Secret names are limited to 64 characters in length, must be alphanumeric (with underscores) and are automatically uppercased.".to_string()).into());
     }
 
     Ok(())
 }
 
-pub fn format_secrets(secrets: &Vec<Secret>, title: bool) -> Vec<String> {
+/// Formats the secrets as a table, with the deployments using each one when
+/// `usage` is passed.
+pub fn format_secrets(
+    secrets: &Vec<Secret>,
+    usage: Option<&[SecretReference]>,
+    title: bool,
+) -> Vec<String> {
     let mut tw = TabWriter::new(vec![]);
 
     if title {
-        writeln!(&mut tw, "NAME\tID\tCREATED").unwrap();
+        if usage.is_some() {
+            writeln!(&mut tw, "NAME\tID\tCREATED\tUSED BY").unwrap();
+        } else {
+            writeln!(&mut tw, "NAME\tID\tCREATED").unwrap();
+        }
     }
 
+    let used_by = usage.map(group_by_secret);
+
     for secret in secrets {
-        writeln!(
+        write!(
             &mut tw,
             "{}\t{}\t{}",
             secret.name, secret.id, secret.created_at
         )
         .unwrap();
+
+        if let Some(ref used_by) = used_by {
+            let deployments = used_by
+                .get(&secret.name.to_uppercase())
+                .map(|d| d.iter().cloned().collect::<Vec<_>>().join(", "))
+                .unwrap_or_else(|| "-".to_string());
+
+            write!(&mut tw, "\t{deployments}").unwrap();
+        }
+
+        writeln!(&mut tw).unwrap();
     }
 
     String::from_utf8(tw.into_inner().unwrap())
@@ -44,3 +100,101 @@ pub fn get_secret_name(secret: &str) -> Option<String> {
 
     regex.captures(secret).map(|c| c[1].to_string())
 }
+
+/// Every `${secrets.NAME}` env value of the deployments, sorted by secret.
+pub fn find_secret_references(deployments: &[Deployment]) -> Vec<SecretReference> {
+    let mut references = deployments
+        .iter()
+        .flat_map(|deployment| {
+            deployment.config.env.iter().filter_map(|(key, value)| {
+                Some(SecretReference {
+                    secret: get_secret_name(value)?.to_uppercase(),
+                    deployment: deployment.name.clone(),
+                    variable: key.clone(),
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    references.sort_by(|a, b| {
+        (&a.secret, &a.deployment, &a.variable).cmp(&(&b.secret, &b.deployment, &b.variable))
+    });
+
+    references
+}
+
+pub async fn get_secret_references(
+    http: &HttpClient,
+    project_id: &str,
+) -> Result<Vec<SecretReference>> {
+    let deployments = get_all_deployments(http, project_id).await?;
+
+    Ok(find_secret_references(&deployments))
+}
+
+/// Maps uppercased secret names to the names of the deployments using them.
+pub fn group_by_secret(references: &[SecretReference]) -> BTreeMap<String, BTreeSet<String>> {
+    let mut grouped = BTreeMap::<_, BTreeSet<_>>::new();
+
+    for reference in references {
+        grouped
+            .entry(reference.secret.clone())
+            .or_default()
+            .insert(reference.deployment.clone());
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_find_secret_references() {
+        let mut api = Deployment {
+            name: "api".to_string(),
+            ..Default::default()
+        };
+        api.config.env = HashMap::from([
+            ("PORT".to_string(), "8080".to_string()),
+            ("DATABASE_URL".to_string(), "${secrets.db_url}".to_string()),
+        ]);
+
+        let mut web = Deployment {
+            name: "web".to_string(),
+            ..Default::default()
+        };
+        web.config.env = HashMap::from([
+            ("API_URL".to_string(), "${secrets.DB_URL}".to_string()),
+            ("TOKEN".to_string(), "${secrets.TOKEN}".to_string()),
+        ]);
+
+        let references = find_secret_references(&[web, api]);
+        let secrets = references
+            .iter()
+            .map(|r| {
+                (
+                    r.secret.as_str(),
+                    r.deployment.as_str(),
+                    r.variable.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            secrets,
+            [
+                ("DB_URL", "api", "DATABASE_URL"),
+                ("DB_URL", "web", "API_URL"),
+                ("TOKEN", "web", "TOKEN"),
+            ]
+        );
+
+        let grouped = group_by_secret(&references);
+        assert_eq!(grouped["DB_URL"].len(), 2);
+        assert_eq!(grouped["TOKEN"].iter().next().unwrap(), "web");
+    }
+}
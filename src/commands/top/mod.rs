@@ -0,0 +1,350 @@
+mod types;
+mod utils;
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use console::{style, Key, Term};
+use futures_util::StreamExt;
+use leap_client_rs::leap::types::Event;
+use leap_client_rs::LeapEdge;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use tokio::time::interval;
+
+use self::types::Dashboard;
+use self::utils::{fetch_dashboard, render};
+use crate::commands::containers::utils::{format_logs, get_container_logs};
+use crate::commands::ignite::utils::{delete_deployment, rollout, scale};
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::arisu::{ArisuClient, ArisuMessage};
+use crate::utils::leap;
+use crate::utils::prompt::{self, Arg};
+
+const LOG_LINES: u64 = 20;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Live dashboard of the deployments in a project")]
+pub struct Options {
+    #[clap(
+        short,
+        long,
+        help = "Seconds between full refreshes, events from Leap refresh it sooner",
+        default_value = "15"
+    )]
+    pub interval: u64,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let term = Term::stdout();
+
+    if !prompt::is_interactive() || !term.is_term() {
+        return Err(HopError::Usage(
+            "The dashboard needs an interactive terminal, use `hop ignite ls` instead".to_string(),
+        )
+        .into());
+    }
+
+    let project = state.ctx.clone().current_project_error()?;
+
+    let mut dashboard = Dashboard {
+        project: project.name.clone(),
+        ..Default::default()
+    };
+
+    let mut leap = match leap::connect(&state, &project.id).await {
+        Ok(leap) => Some(leap),
+        Err(error) => {
+            log::debug!("Failed to connect to Leap: {error}");
+            None
+        }
+    };
+
+    dashboard.live = leap.is_some();
+
+    let (mut keys, ack) = read_keys();
+
+    term.write_str("\x1b[?1049h")?;
+    term.hide_cursor()?;
+
+    let result = run(
+        &state,
+        &project.id,
+        &options,
+        &term,
+        &mut dashboard,
+        &mut leap,
+        &mut keys,
+        &ack,
+    )
+    .await;
+
+    term.show_cursor()?;
+    term.write_str("\x1b[?1049l")?;
+
+    if let Some(mut leap) = leap {
+        leap.close().await;
+    }
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run(
+    state: &State,
+    project_id: &str,
+    options: &Options,
+    term: &Term,
+    dashboard: &mut Dashboard,
+    leap: &mut Option<LeapEdge>,
+    keys: &mut UnboundedReceiver<Key>,
+    ack: &mpsc::Sender<()>,
+) -> Result<()> {
+    let (deployments, details) = fetch_dashboard(&state.http, project_id).await?;
+    dashboard.update(deployments, details);
+    dashboard.updated_at = chrono::Local::now().format("%H:%M:%S").to_string();
+
+    let mut refresh = interval(Duration::from_secs(options.interval.max(1)));
+    // redraws for uptimes and picks up Leap events in batches
+    let mut tick = interval(Duration::from_secs(1));
+    let mut dirty = false;
+    let mut drawn = vec![];
+
+    loop {
+        if dirty {
+            dirty = false;
+
+            match fetch_dashboard(&state.http, project_id).await {
+                Ok((deployments, details)) => {
+                    dashboard.update(deployments, details);
+                    dashboard.updated_at = chrono::Local::now().format("%H:%M:%S").to_string();
+                }
+                Err(error) => dashboard.status = Some(failed(&error)),
+            }
+        }
+
+        let (height, width) = term.size();
+        let lines = render(dashboard, height.into(), width.into());
+
+        if lines != drawn {
+            term.clear_screen()?;
+            term.write_str(&lines.join("\n"))?;
+            drawn = lines;
+        }
+
+        tokio::select! {
+            _ = refresh.tick() => dirty = true,
+
+            _ = tick.tick() => {}
+
+            event = async {
+                match leap.as_mut() {
+                    Some(leap) => leap.listen().await,
+                    None => std::future::pending().await,
+                }
+            } => match event {
+                Some(Event::Message(capsuled)) if capsuled.channel.as_deref() == Some(project_id) => {
+                    dirty = true;
+                }
+
+                Some(_) => {}
+
+                None => {
+                    *leap = None;
+                    dashboard.live = false;
+                }
+            },
+
+            key = keys.recv() => {
+                let Some(key) = key else {
+                    return Ok(());
+                };
+
+                match key {
+                    Key::Char('q') | Key::Escape => return Ok(()),
+                    Key::ArrowUp | Key::Char('k') => dashboard.move_selection(-1),
+                    Key::ArrowDown | Key::Char('j') => dashboard.move_selection(1),
+                    Key::Enter | Key::Char(' ') => dashboard.toggle_expanded(),
+
+                    Key::Char(action @ ('s' | 'r' | 'l' | 'd')) => {
+                        if let Some(deployment) = dashboard.selected().cloned() {
+                            term.clear_screen()?;
+                            term.show_cursor()?;
+
+                            let result = match action {
+                                's' => scale_deployment(state, dashboard).await,
+                                'r' => rollout(&state.http, &deployment.id)
+                                    .await
+                                    .map(|_| Some(format!("Rolling out `{}`", deployment.name))),
+                                'l' => follow_logs(state, dashboard, term, keys, ack).await,
+                                _ => delete(state, &deployment.name, &deployment.id).await,
+                            };
+
+                            term.hide_cursor()?;
+                            drawn.clear();
+
+                            match result {
+                                Ok(Some(status)) => {
+                                    dashboard.status = Some(status);
+                                    dirty = true;
+                                }
+                                Ok(None) => {}
+                                Err(error) => dashboard.status = Some(failed(&error)),
+                            }
+                        }
+                    }
+
+                    _ => {}
+                }
+
+                // the key is handled, read the next one
+                ack.send(()).ok();
+            }
+        }
+    }
+}
+
+/// Reads keys on a separate thread, one at a time: the next key is only read
+/// once the previous one was acknowledged, so prompts can use the terminal.
+fn read_keys() -> (UnboundedReceiver<Key>, mpsc::Sender<()>) {
+    let (tx, rx) = unbounded_channel();
+    let (ack_tx, ack_rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let term = Term::stdout();
+
+        while let Ok(key) = term.read_key() {
+            if tx.send(key).is_err() || ack_rx.recv().is_err() {
+                break;
+            }
+        }
+    });
+
+    (rx, ack_tx)
+}
+
+fn failed(error: &anyhow::Error) -> String {
+    style(format!("Failed: {error}")).red().to_string()
+}
+
+async fn scale_deployment(state: &State, dashboard: &Dashboard) -> Result<Option<String>> {
+    let deployment = dashboard.selected().unwrap();
+
+    if deployment.is_autoscaled() {
+        return Ok(Some(format!(
+            "Deployment `{}` is autoscaled, use `hop ignite scale` to adjust its bounds",
+            deployment.name
+        )));
+    }
+
+    let count = prompt::input::<u64>(
+        format!("Number of containers for `{}`", deployment.name),
+        Arg::new("scale", "<SCALE>"),
+    )?
+    .default(deployment.target_container_count)
+    .interact_text()?;
+
+    scale(&state.http, &deployment.id, count).await?;
+
+    Ok(Some(format!(
+        "Scaling `{}` to {count} containers",
+        deployment.name
+    )))
+}
+
+async fn delete(state: &State, name: &str, deployment_id: &str) -> Result<Option<String>> {
+    if !prompt::confirm(
+        format!("Are you sure you want to delete deployment `{name}`?"),
+        false,
+        "--force",
+    )? {
+        return Ok(None);
+    }
+
+    delete_deployment(&state.http, deployment_id).await?;
+
+    Ok(Some(format!("Deleted deployment `{name}`")))
+}
+
+/// Streams the logs of a container of the selected deployment until a key is pressed.
+async fn follow_logs(
+    state: &State,
+    dashboard: &Dashboard,
+    term: &Term,
+    keys: &mut UnboundedReceiver<Key>,
+    ack: &mpsc::Sender<()>,
+) -> Result<Option<String>> {
+    let deployment = dashboard.selected().unwrap();
+
+    let containers = dashboard
+        .details
+        .get(&deployment.id)
+        .map(|details| details.containers.as_slice())
+        .unwrap_or_default();
+
+    let container = match containers {
+        [] => {
+            return Ok(Some(format!(
+                "Deployment `{}` has no containers",
+                deployment.name
+            )))
+        }
+
+        [container] => container,
+
+        _ => {
+            let ids = containers.iter().map(|c| &c.id).collect::<Vec<_>>();
+
+            &containers[prompt::select(
+                "Select a container",
+                &ids,
+                0,
+                Arg::new("container", "<CONTAINER>"),
+            )?]
+        }
+    };
+
+    term.clear_screen()?;
+    term.hide_cursor()?;
+    term.write_line(
+        &style(format!(
+            "Logs of `{}`, press any key to go back",
+            container.id
+        ))
+        .dim()
+        .to_string(),
+    )?;
+
+    let logs = get_container_logs(&state.http, &container.id, LOG_LINES, "desc").await?;
+
+    for line in format_logs(&logs, true, false, false) {
+        term.write_line(&line)?;
+    }
+
+    let token = state.token().unwrap();
+    let mut arisu = ArisuClient::new(&container.id, &token).await?;
+
+    // let the key thread read the key that stops the logs
+    ack.send(()).ok();
+
+    loop {
+        tokio::select! {
+            _ = keys.recv() => break,
+
+            message = arisu.next() => match message {
+                Some(ArisuMessage::Out(log)) => {
+                    term.write_str(&format_logs(&[log], true, false, false)[0])?;
+                }
+                Some(ArisuMessage::ServiceMessage(_)) => {}
+                None => {
+                    keys.recv().await;
+                    break;
+                }
+            },
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::commands::containers::types::Container;
+use crate::commands::gateways::types::Gateway;
+use crate::commands::ignite::health::types::HealthCheckState;
+use crate::commands::ignite::types::Deployment;
+
+/// What is shown under a deployment once it is expanded.
+#[derive(Debug, Default)]
+pub struct Details {
+    pub containers: Vec<Container>,
+    pub gateways: Vec<Gateway>,
+    pub health: Vec<HealthCheckState>,
+}
+
+#[derive(Debug, Default)]
+pub struct Dashboard {
+    pub project: String,
+    pub deployments: Vec<Deployment>,
+    /// keyed by deployment ID
+    pub details: HashMap<String, Details>,
+    pub selected: usize,
+    /// IDs of the expanded deployments
+    pub expanded: HashSet<String>,
+    /// whether updates come from Leap or only from polling
+    pub live: bool,
+    pub updated_at: String,
+    /// result of the last action, shown above the key bindings
+    pub status: Option<String>,
+}
+
+impl Dashboard {
+    pub fn selected(&self) -> Option<&Deployment> {
+        self.deployments.get(self.selected)
+    }
+
+    /// Replaces the data, keeping the same deployment selected when it still exists.
+    pub fn update(&mut self, deployments: Vec<Deployment>, details: HashMap<String, Details>) {
+        let selected = self.selected().map(|d| d.id.clone());
+
+        self.deployments = deployments;
+        self.details = details;
+        self.expanded
+            .retain(|id| self.deployments.iter().any(|d| &d.id == id));
+
+        self.selected = selected
+            .and_then(|id| self.deployments.iter().position(|d| d.id == id))
+            .unwrap_or(self.selected)
+            .min(self.deployments.len().saturating_sub(1));
+    }
+
+    pub fn move_selection(&mut self, by: isize) {
+        if self.deployments.is_empty() {
+            return;
+        }
+
+        self.selected = self
+            .selected
+            .saturating_add_signed(by)
+            .min(self.deployments.len() - 1);
+    }
+
+    pub fn toggle_expanded(&mut self) {
+        let Some(id) = self.selected().map(|d| d.id.clone()) else {
+            return;
+        };
+
+        if !self.expanded.remove(&id) {
+            self.expanded.insert(id);
+        }
+    }
+}
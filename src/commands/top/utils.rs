@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use console::{style, truncate_str};
+use futures_util::{stream, StreamExt};
+
+use super::types::{Dashboard, Details};
+use crate::commands::containers::utils::{format_containers, get_all_containers};
+use crate::commands::gateways::util::get_all_gateways;
+use crate::commands::ignite::health::utils::get_health_state;
+use crate::commands::ignite::types::Deployment;
+use crate::commands::ignite::utils::{format_deployments, get_all_deployments};
+use crate::state::http::HttpClient;
+
+const FETCH_CONCURRENCY: usize = 5;
+
+pub const KEY_BINDINGS: &str =
+    "↑/↓ move  enter expand  s scale  r rollout  l logs  d delete  q quit";
+
+/// Fetches the deployments of a project with the containers, gateways
+/// and health state of each of them.
+pub async fn fetch_dashboard(
+    http: &HttpClient,
+    project_id: &str,
+) -> Result<(Vec<Deployment>, HashMap<String, Details>)> {
+    let deployments = get_all_deployments(http, project_id).await?;
+
+    let results = stream::iter(&deployments)
+        .map(|deployment| async move {
+            let (containers, gateways, health) = tokio::try_join!(
+                get_all_containers(http, &deployment.id),
+                get_all_gateways(http, &deployment.id),
+                get_health_state(http, &deployment.id),
+            )?;
+
+            Ok::<_, anyhow::Error>((
+                deployment.id.clone(),
+                Details {
+                    containers,
+                    gateways,
+                    health,
+                },
+            ))
+        })
+        .buffer_unordered(FETCH_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let details = results.into_iter().collect::<Result<HashMap<_, _>>>()?;
+
+    Ok((deployments, details))
+}
+
+/// Lines of the dashboard for a terminal of `height` rows and `width` columns.
+pub fn render(dashboard: &Dashboard, height: usize, width: usize) -> Vec<String> {
+    let mut header = vec![
+        format!(
+            "{} {}  {}",
+            style("Project").bold(),
+            dashboard.project,
+            style(format!(
+                "updated {}, {}",
+                dashboard.updated_at,
+                if dashboard.live { "live" } else { "polling" }
+            ))
+            .dim()
+        ),
+        String::new(),
+    ];
+
+    let mut body = vec![];
+    let mut selected_line = 0;
+
+    if dashboard.deployments.is_empty() {
+        body.push("No deployments found".to_string());
+    } else {
+        let mut rows = format_deployments(&dashboard.deployments, true).into_iter();
+
+        header.push(format!("  {}", rows.next().unwrap_or_default()));
+
+        for (idx, (deployment, row)) in dashboard.deployments.iter().zip(rows).enumerate() {
+            let expanded = dashboard.expanded.contains(&deployment.id);
+            let row = format!("{} {row}", if expanded { "▾" } else { "▸" });
+
+            if idx == dashboard.selected {
+                selected_line = body.len();
+                body.push(style(row).reverse().to_string());
+            } else {
+                body.push(row);
+            }
+
+            if expanded {
+                body.extend(
+                    format_details(dashboard.details.get(&deployment.id))
+                        .into_iter()
+                        .map(|line| format!("    {line}")),
+                );
+            }
+        }
+    }
+
+    let mut footer = vec![String::new()];
+
+    if let Some(ref status) = dashboard.status {
+        footer.push(status.clone());
+    }
+
+    footer.push(style(KEY_BINDINGS).dim().to_string());
+
+    // scroll so the selected deployment stays visible
+    let room = height.saturating_sub(header.len() + footer.len()).max(1);
+    let start = (selected_line + 1).saturating_sub(room);
+
+    header
+        .into_iter()
+        .chain(body.into_iter().skip(start).take(room))
+        .chain(footer)
+        .map(|line| truncate_str(&line, width, "").to_string())
+        .collect()
+}
+
+fn format_details(details: Option<&Details>) -> Vec<String> {
+    let Some(details) = details else {
+        return vec!["-".to_string()];
+    };
+
+    let mut lines = vec![];
+
+    if details.containers.is_empty() {
+        lines.push("No containers".to_string());
+    } else {
+        lines.extend(format_containers(&details.containers, true));
+    }
+
+    if !details.health.is_empty() {
+        lines.push(format!(
+            "Health: {}",
+            details
+                .health
+                .iter()
+                .map(|s| format!("{} {}", s.container_id, s.state))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    for gateway in &details.gateways {
+        lines.push(format!(
+            "Gateway {}: {}",
+            gateway.name.as_deref().unwrap_or(&gateway.id),
+            gateway.full_url()
+        ));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment(id: &str, name: &str) -> Deployment {
+        Deployment {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        console::set_colors_enabled(false);
+
+        let mut dashboard = Dashboard {
+            project: "demo".to_string(),
+            deployments: (1..=10)
+                .map(|i| deployment(&format!("deployment_{i}"), &format!("app{i}")))
+                .collect(),
+            ..Default::default()
+        };
+
+        dashboard.move_selection(1);
+        dashboard.toggle_expanded();
+
+        let lines = render(&dashboard, 100, 80);
+        assert!(lines[0].starts_with("Project demo"));
+        assert!(lines[2].starts_with("  NAME"));
+        assert!(lines[4].starts_with("▾ app2"));
+        assert_eq!(lines[5], "    -");
+        assert_eq!(lines.last().unwrap(), KEY_BINDINGS);
+
+        // only 3 deployments fit, the selected one is the last of them
+        dashboard.move_selection(5);
+        let lines = render(&dashboard, 8, 80);
+        assert_eq!(lines.len(), 8);
+        assert!(lines[5].starts_with("▸ app7"));
+
+        dashboard.update(vec![deployment("deployment_7", "app7")], HashMap::new());
+        assert_eq!(dashboard.selected, 0);
+        assert!(dashboard.expanded.is_empty());
+    }
+}
@@ -11,14 +11,13 @@ use tokio::net::TcpListener;
 use tokio::sync::mpsc::unbounded_channel;
 
 use self::utils::{parse_publish, TonneruSocket};
-use super::ignite::utils::get_all_deployments;
+use crate::commands::containers::types::Container;
 use crate::commands::ignite::types::Deployment;
-use crate::commands::ignite::utils::{format_deployments, get_deployment};
-use crate::commands::tunnel::types::Prefix;
-use crate::commands::tunnel::utils::{
-    add_entry_to_hosts, get_id_with_prefix, remove_entry_from_hosts,
-};
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::commands::tunnel::utils::{add_entry_to_hosts, remove_entry_from_hosts};
 use crate::state::State;
+use crate::utils::prompt::{self, Arg};
+use crate::utils::resolve;
 use crate::utils::urlify;
 
 // TLS Socker Uri
@@ -29,7 +28,7 @@ const DOMAIN_SUFFIX: &str = "hop";
 #[derive(Debug, Parser)]
 #[clap(about = "Access your application via a tunnel")]
 pub struct Options {
-    #[clap(help = "Resource to tunnel to, can be a deployment name, ID")]
+    #[clap(help = "Resource to tunnel to, can be a deployment name or ID, or a container ID")]
     pub deployment: Option<String>,
     #[clap(long, help = "Publish a container's port(s) to the host", value_parser = parse_publish)]
     pub publish: Option<(IpAddr, u16, u16)>,
@@ -38,38 +37,21 @@ pub struct Options {
 }
 
 pub async fn handle(options: &Options, state: State) -> Result<()> {
-    let project = state.ctx.clone().current_project_error();
-
-    let deployment = match get_id_with_prefix(options.deployment.as_deref()) {
-        Some((Prefix::Deployment, id)) => get_deployment(&state.http, &id).await?,
-        Some((Prefix::Container, id)) => Deployment {
+    let deployment = match options.deployment {
+        Some(ref id) if resolve::is_id::<Container>(id) => Deployment {
             id: id.clone(),
-            name: id,
+            name: id.clone(),
             container_count: 1,
             ..Default::default()
         },
-        unknown => {
-            let deployments = get_all_deployments(&state.http, &project.id).await?;
-            ensure!(!deployments.is_empty(), "No deployments found.");
-
-            if let Some((_, name)) = unknown {
-                deployments
-                    .iter()
-                    .find(|d| d.name.to_lowercase() == name.to_lowercase())
-                    .ok_or_else(|| anyhow!("Deployment not found."))?
-                    .clone()
-            } else {
-                let deployments_fmt = format_deployments(&deployments, false);
 
-                let idx = dialoguer::Select::new()
-                    .with_prompt("Select a deployment")
-                    .items(&deployments_fmt)
-                    .default(0)
-                    .interact_opt()?
-                    .ok_or_else(|| anyhow!("No deployment selected."))?;
-
-                deployments[idx].clone()
-            }
+        ref query => {
+            resolve_deployment(
+                &state,
+                query.as_deref(),
+                Arg::new("deployment", "<DEPLOYMENT>"),
+            )
+            .await?
         }
     };
 
@@ -81,10 +63,12 @@ pub async fn handle(options: &Options, state: State) -> Result<()> {
     let (ip_address, local_port, remote_port) = if let Some(publish_values) = options.publish {
         publish_values
     } else {
-        let local_address = dialoguer::Input::<IpAddr>::new()
-            .with_prompt("Local IP address to bind to")
-            .default(IpAddr::from([127, 0, 0, 1]))
-            .interact()?;
+        let local_address = prompt::input::<IpAddr>(
+            "Local IP address to bind to",
+            Arg::new("publish", "--publish"),
+        )?
+        .default(IpAddr::from([127, 0, 0, 1]))
+        .interact()?;
 
         let mut ports = HashSet::new();
 
@@ -113,17 +97,16 @@ pub async fn handle(options: &Options, state: State) -> Result<()> {
             let idx = if ports.len() == 1 {
                 0
             } else {
-                dialoguer::Select::new()
-                    .with_prompt("Select a local port")
-                    .items(&ports)
-                    .default(0)
-                    .interact_opt()?
-                    .ok_or_else(|| anyhow!("No port selected."))?
+                prompt::select(
+                    "Select a local port",
+                    &ports,
+                    0,
+                    Arg::new("port", "--publish"),
+                )?
             };
 
             if idx == ports.len() - 1 {
-                dialoguer::Input::<u16>::new()
-                    .with_prompt("Local port number")
+                prompt::input::<u16>("Local port number", Arg::new("port", "--publish"))?
                     .interact()?
             } else {
                 ports[idx].parse()?
@@ -134,17 +117,16 @@ pub async fn handle(options: &Options, state: State) -> Result<()> {
             let idx = if ports.len() == 1 {
                 0
             } else {
-                dialoguer::Select::new()
-                    .with_prompt("Select the remote port")
-                    .items(&ports)
-                    .default(0)
-                    .interact_opt()?
-                    .ok_or_else(|| anyhow!("No port selected."))?
+                prompt::select(
+                    "Select the remote port",
+                    &ports,
+                    0,
+                    Arg::new("port", "--publish"),
+                )?
             };
 
             if idx == ports.len() - 1 {
-                dialoguer::Input::<u16>::new()
-                    .with_prompt("Remote port number")
+                prompt::input::<u16>("Remote port number", Arg::new("port", "--publish"))?
                     .interact()?
             } else {
                 ports[idx].parse()?
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+
+use super::utils::{wait_for, Progress};
+use crate::commands::ignite::builds::types::BuildState;
+use crate::commands::ignite::builds::utils::get_build;
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until a build succeeds, fails if it does not")]
+pub struct Options {
+    #[clap(help = "ID of the build")]
+    pub build: String,
+}
+
+pub async fn handle(options: Options, state: State, timeout: Duration) -> Result<()> {
+    wait_for(
+        &state,
+        &format!("build `{}`", options.build),
+        timeout,
+        || async {
+            let build = get_build(&state.http, &options.build).await?;
+
+            match build.state {
+                BuildState::Succeeded => Ok(Progress::Done(())),
+                BuildState::Pending => Ok(Progress::Waiting(build.state.to_string())),
+                BuildState::Failed | BuildState::Cancelled => {
+                    bail!("Build `{}` {}", options.build, build.state)
+                }
+            }
+        },
+    )
+    .await?;
+
+    log::info!("Build `{}` succeeded", options.build);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{wait_for, Progress};
+use crate::commands::containers::types::ContainerState;
+use crate::commands::containers::utils::get_container;
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until a container reaches a state")]
+pub struct Options {
+    #[clap(help = "ID of the container")]
+    pub container: String,
+
+    #[clap(
+        long,
+        help = "State to wait for, e.g. `running` or `exited`",
+        default_value = "running"
+    )]
+    pub state: ContainerState,
+}
+
+pub async fn handle(options: Options, state: State, timeout: Duration) -> Result<()> {
+    wait_for(
+        &state,
+        &format!("container `{}`", options.container),
+        timeout,
+        || async {
+            let container = get_container(&state.http, &options.container).await?;
+
+            Ok(if container.state == options.state {
+                Progress::Done(())
+            } else {
+                Progress::Waiting(container.state.to_string())
+            })
+        },
+    )
+    .await?;
+
+    log::info!("Container `{}` is {}", options.container, options.state);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{wait_for, Progress};
+use crate::commands::containers::types::ContainerState;
+use crate::commands::containers::utils::get_all_containers;
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until a deployment has enough running containers")]
+pub struct Options {
+    #[clap(help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(
+        long,
+        help = "Number of running containers to wait for, defaults to the target of the deployment"
+    )]
+    pub running: Option<u64>,
+}
+
+pub async fn handle(options: Options, state: State, timeout: Duration) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
+
+    let target = options.running.unwrap_or(deployment.target_container_count);
+
+    let running = wait_for(
+        &state,
+        &format!("deployment `{}`", deployment.name),
+        timeout,
+        || async {
+            let running = get_all_containers(&state.http, &deployment.id)
+                .await?
+                .iter()
+                .filter(|c| c.state == ContainerState::Running)
+                .count() as u64;
+
+            Ok(if running >= target {
+                Progress::Done(running)
+            } else {
+                Progress::Waiting(format!("{running}/{target} containers running"))
+            })
+        },
+    )
+    .await?;
+
+    log::info!(
+        "Deployment `{}` has {running} running containers",
+        deployment.name
+    );
+
+    Ok(())
+}
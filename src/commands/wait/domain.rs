@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{wait_for, Progress};
+use crate::commands::domains::types::{Domain, DomainState};
+use crate::commands::gateways::util::get_all_gateways;
+use crate::commands::ignite::utils::{get_all_deployments, resolve_deployment};
+use crate::state::State;
+use crate::utils::prompt::Arg;
+use crate::utils::resolve;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until a domain reaches a state")]
+pub struct Options {
+    #[clap(help = "The domain or its ID")]
+    pub domain: String,
+
+    #[clap(
+        long,
+        help = "State to wait for, `pending`, `valid_cname` or `ssl_active`",
+        default_value = "ssl_active"
+    )]
+    pub state: DomainState,
+
+    #[clap(
+        short,
+        long,
+        help = "ID or name of the deployment the domain is attached to, defaults to all of them"
+    )]
+    pub deployment: Option<String>,
+}
+
+pub async fn handle(options: Options, state: State, timeout: Duration) -> Result<()> {
+    let deployments = match options.deployment {
+        Some(ref deployment) => vec![
+            resolve_deployment(
+                &state,
+                Some(deployment),
+                Arg::new("deployment", "--deployment"),
+            )
+            .await?,
+        ],
+
+        None => {
+            let project = state.ctx.clone().current_project_error()?;
+
+            get_all_deployments(&state.http, &project.id).await?
+        }
+    };
+
+    let domain = wait_for(
+        &state,
+        &format!("domain `{}`", options.domain),
+        timeout,
+        || async {
+            let mut domains: Vec<Domain> = vec![];
+
+            for deployment in &deployments {
+                for gateway in get_all_gateways(&state.http, &deployment.id).await? {
+                    domains.extend(gateway.domains);
+                }
+            }
+
+            let domain = resolve::find(&domains, &options.domain)?;
+
+            Ok(if domain.state == options.state {
+                Progress::Done(domain.domain.clone())
+            } else {
+                Progress::Waiting(domain.state.to_string())
+            })
+        },
+    )
+    .await?;
+
+    log::info!("Domain `{domain}` is {}", options.state);
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+
+use super::utils::{wait_for, Progress};
+use crate::commands::ignite::health::utils::get_health_state;
+use crate::commands::ignite::utils::resolve_deployment;
+use crate::state::State;
+use crate::utils::prompt::Arg;
+
+const HEALTHY: &str = "healthy";
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until the health checks of a deployment pass")]
+pub struct Options {
+    #[clap(help = "ID or name of the deployment")]
+    pub deployment: Option<String>,
+
+    #[clap(long, help = "Wait until every health check passes, the default")]
+    pub healthy: bool,
+
+    #[clap(
+        long,
+        help = "Wait until any health check fails instead",
+        conflicts_with = "healthy"
+    )]
+    pub unhealthy: bool,
+}
+
+pub async fn handle(options: Options, state: State, timeout: Duration) -> Result<()> {
+    let deployment = resolve_deployment(
+        &state,
+        options.deployment.as_deref(),
+        Arg::new("deployment", "<DEPLOYMENT>"),
+    )
+    .await?;
+
+    wait_for(
+        &state,
+        &format!("the health of deployment `{}`", deployment.name),
+        timeout,
+        || async {
+            let states = get_health_state(&state.http, &deployment.id).await?;
+            let healthy = states.iter().filter(|s| s.state == HEALTHY).count();
+
+            let done = if options.unhealthy {
+                healthy < states.len()
+            } else {
+                !states.is_empty() && healthy == states.len()
+            };
+
+            Ok(if done {
+                Progress::Done(())
+            } else if states.is_empty() {
+                Progress::Waiting("not checked yet".to_string())
+            } else {
+                Progress::Waiting(format!("{healthy}/{} checks healthy", states.len()))
+            })
+        },
+    )
+    .await?;
+
+    log::info!(
+        "Deployment `{}` is {}",
+        deployment.name,
+        if options.unhealthy {
+            "unhealthy"
+        } else {
+            HEALTHY
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+mod build;
+mod container;
+mod deployment;
+mod domain;
+mod health;
+mod rollout;
+mod utils;
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::state::State;
+use crate::utils::parse_duration;
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Deployment(deployment::Options),
+    Container(container::Options),
+    Build(build::Options),
+    Rollout(rollout::Options),
+    Domain(domain::Options),
+    Health(health::Options),
+}
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until a resource reaches a state, for scripts")]
+pub struct Options {
+    #[clap(subcommand)]
+    pub commands: Commands,
+
+    #[clap(
+        long,
+        help = "How long to wait before failing, e.g. `90s` or `10m`",
+        default_value = "5m",
+        value_parser = parse_duration,
+        global = true
+    )]
+    pub timeout: Duration,
+}
+
+pub async fn handle(options: Options, state: State) -> Result<()> {
+    let timeout = options.timeout;
+
+    match options.commands {
+        Commands::Deployment(options) => deployment::handle(options, state, timeout).await,
+        Commands::Container(options) => container::handle(options, state, timeout).await,
+        Commands::Build(options) => build::handle(options, state, timeout).await,
+        Commands::Rollout(options) => rollout::handle(options, state, timeout).await,
+        Commands::Domain(options) => domain::handle(options, state, timeout).await,
+        Commands::Health(options) => health::handle(options, state, timeout).await,
+    }
+}
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+
+use super::utils::{wait_for, Progress};
+use crate::commands::ignite::types::RolloutState;
+use crate::commands::ignite::utils::get_rollout;
+use crate::state::State;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Wait until a rollout finishes, fails if it does not")]
+pub struct Options {
+    #[clap(help = "ID of the rollout")]
+    pub rollout: String,
+}
+
+pub async fn handle(options: Options, state: State, timeout: Duration) -> Result<()> {
+    wait_for(
+        &state,
+        &format!("rollout `{}`", options.rollout),
+        timeout,
+        || async {
+            let rollout = get_rollout(&state.http, &options.rollout).await?;
+
+            match rollout.state {
+                RolloutState::Finished => Ok(Progress::Done(())),
+                RolloutState::Pending => Ok(Progress::Waiting(rollout.state.to_string())),
+                RolloutState::Failed => bail!("Rollout `{}` failed", options.rollout),
+            }
+        },
+    )
+    .await?;
+
+    log::info!("Rollout `{}` finished", options.rollout);
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use leap_client_rs::leap::types::Event;
+use leap_client_rs::LeapEdge;
+use tokio::time::{sleep, sleep_until, Instant};
+
+use crate::error::HopError;
+use crate::state::State;
+use crate::utils::leap;
+
+/// Checks between events, in case Leap misses one.
+const LEAP_POLL_INTERVAL: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+pub enum Progress<T> {
+    Done(T),
+    /// not there yet, with a description of the current state
+    Waiting(String),
+}
+
+/// Runs `check` until it is done, after every event in the project channel
+/// and every few seconds. Fails with `HopError::Timeout` after `timeout`.
+pub async fn wait_for<T, F, Fut>(
+    state: &State,
+    what: &str,
+    timeout: Duration,
+    mut check: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Progress<T>>>,
+{
+    let deadline = Instant::now() + timeout;
+
+    let mut leap = match state.ctx.current_project() {
+        Some(project) => match leap::connect(state, &project.id).await {
+            Ok(leap) => Some((leap, project.id)),
+            Err(error) => {
+                log::debug!("Failed to connect to Leap, polling instead: {error}");
+                None
+            }
+        },
+        None => None,
+    };
+
+    let result = loop {
+        let last = match check().await {
+            Ok(Progress::Done(value)) => break Ok(value),
+            Ok(Progress::Waiting(last)) => last,
+            Err(error) => break Err(error),
+        };
+
+        log::debug!("Waiting for {what}: {last}");
+
+        let interval = if leap.is_some() {
+            LEAP_POLL_INTERVAL
+        } else {
+            POLL_INTERVAL
+        };
+
+        tokio::select! {
+            _ = sleep_until(deadline) => {
+                break Err(HopError::Timeout(format!(
+                    "Timed out after {}s waiting for {what} ({last})",
+                    timeout.as_secs()
+                ))
+                .into());
+            }
+
+            _ = sleep(interval) => {}
+
+            _ = project_event(&mut leap) => {}
+        }
+    };
+
+    if let Some((mut leap, _)) = leap {
+        leap.close().await;
+    }
+
+    result
+}
+
+/// Resolves on the next message in the project channel, never if Leap is gone.
+async fn project_event(leap: &mut Option<(LeapEdge, String)>) {
+    loop {
+        let Some((edge, project_id)) = leap.as_mut() else {
+            return std::future::pending().await;
+        };
+
+        match edge.listen().await {
+            Some(Event::Message(capsuled)) if capsuled.channel.as_ref() == Some(project_id) => {
+                return;
+            }
+
+            Some(_) => {}
+
+            None => {
+                log::debug!("Lost the connection to Leap, polling instead");
+                *leap = None;
+            }
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::state::State;
 #[clap(about = "Get information about the current user")]
 pub struct Options {}
 
-pub fn handle(_options: &Options, state: State) -> Result<()> {
+pub async fn handle(_options: &Options, state: State) -> Result<()> {
     let authorized = state
         .ctx
         .current
@@ -25,7 +25,7 @@ pub fn handle(_options: &Options, state: State) -> Result<()> {
     let project = state.ctx.current_project();
 
     match project {
-        Some(_) => info::handle(&info::Options {}, state),
+        Some(_) => info::handle(&info::Options::default(), state).await?,
         None => {
             log::warn!(
                 "No project is currently selected. Please run `{EXEC_NAME} projects switch` first."
@@ -6,6 +6,7 @@ pub const EXEC_NAME: &str = "hop";
 #[cfg(windows)]
 pub const EXEC_NAME: &str = "hop.exe";
 pub const LEAP_PROJECT: &str = "project_MzA0MDgwOTQ2MDEwODQ5NzQ";
+pub const HOP_BUILD_BASE_URL: &str = "https://builder.hop.io/v1";
 
 #[cfg(windows)]
 pub const DEFAULT_EDITOR: &str = "notepad.exe";
@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+use hyper::StatusCode;
+
+use crate::state::http::types::ErrorResponse;
+
+/// Failures that scripts can branch on, each kind exits with its own code.
+///
+/// | Code | Kind           | Meaning                                            |
+/// |------|----------------|----------------------------------------------------|
+/// | 1    | -              | Any other error                                    |
+/// | 2    | `Usage`        | Invalid usage or a missing argument without input  |
+/// | 3    | `Local`        | Local problem, e.g. no project or a broken file    |
+/// | 4    | `Unauthorized` | Missing, invalid or expired credentials            |
+/// | 5    | `Forbidden`    | Not allowed to access the resource                 |
+/// | 6    | `NotFound`     | The resource does not exist                        |
+/// | 7    | `Validation`   | The API rejected the request as invalid            |
+/// | 8    | `Conflict`     | The resource already exists or is in use           |
+/// | 9    | `RateLimited`  | Too many requests, retries were exhausted          |
+/// | 10   | `Network`      | Connection failures, timeouts and server errors    |
+/// | 11   | `Timeout`      | Gave up waiting for a resource to reach a state    |
+///
+/// `Exited` is used when a container or command run by hop fails, it exits
+/// with the code of what failed so wrappers like `hop ignite run` are
+/// transparent to scripts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HopError {
+    Usage(String),
+    NotFound(String),
+    Unauthorized(String),
+    Forbidden(String),
+    Validation(String),
+    RateLimited(String),
+    Conflict(String),
+    Network(String),
+    Local(String),
+    Timeout(String),
+    /// message and the exit code to pass through
+    Exited(String, i32),
+}
+
+impl HopError {
+    /// Exit code used when no `HopError` is found in the error chain.
+    pub const DEFAULT_EXIT_CODE: i32 = 1;
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Usage(_) => 2,
+            Self::Local(_) => 3,
+            Self::Unauthorized(_) => 4,
+            Self::Forbidden(_) => 5,
+            Self::NotFound(_) => 6,
+            Self::Validation(_) => 7,
+            Self::Conflict(_) => 8,
+            Self::RateLimited(_) => 9,
+            Self::Network(_) => 10,
+            Self::Timeout(_) => 11,
+            Self::Exited(_, code) => *code,
+        }
+    }
+
+    /// Classifies a failed API response by its status, falling back to
+    /// the error code in the body for statuses without a direct mapping.
+    pub fn from_response(status: StatusCode, body: Option<ErrorResponse>) -> Self {
+        let (code, message) = match body {
+            Some(body) => (body.error.code, body.error.message),
+            None => (String::new(), format!("Error: HTTP {status}")),
+        };
+
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Unauthorized(message),
+            StatusCode::FORBIDDEN => Self::Forbidden(message),
+            StatusCode::NOT_FOUND => Self::NotFound(message),
+            StatusCode::CONFLICT => Self::Conflict(message),
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited(message),
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Self::Validation(message),
+            status if status.is_server_error() => Self::Network(message),
+
+            _ => match code.as_str() {
+                code if code.contains("not_found") => Self::NotFound(message),
+                code if code.contains("unauthorized") => Self::Unauthorized(message),
+                code if code.contains("forbidden") => Self::Forbidden(message),
+                code if code.contains("conflict") || code.contains("exists") => {
+                    Self::Conflict(message)
+                }
+                _ => Self::Validation(message),
+            },
+        }
+    }
+
+    /// Exit code for any error, looking for a `HopError` in its chain.
+    pub fn exit_code_of(error: &anyhow::Error) -> i32 {
+        error
+            .chain()
+            .find_map(|e| e.downcast_ref::<Self>())
+            .map_or(Self::DEFAULT_EXIT_CODE, Self::exit_code)
+    }
+}
+
+impl Display for HopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Usage(message)
+            | Self::NotFound(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message)
+            | Self::Validation(message)
+            | Self::RateLimited(message)
+            | Self::Conflict(message)
+            | Self::Network(message)
+            | Self::Local(message)
+            | Self::Timeout(message)
+            | Self::Exited(message, _) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for HopError {}
+
+impl From<reqwest::Error> for HopError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Network(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::*;
+    use crate::state::http::types::ErrorContent;
+
+    fn body(code: &str) -> Option<ErrorResponse> {
+        Some(ErrorResponse {
+            error: ErrorContent {
+                code: code.to_string(),
+                message: "message".to_string(),
+            },
+        })
+    }
+
+    #[test]
+    fn test_from_response() {
+        assert_eq!(
+            HopError::from_response(StatusCode::NOT_FOUND, body("")),
+            HopError::NotFound("message".to_string())
+        );
+        assert_eq!(
+            HopError::from_response(StatusCode::BAD_GATEWAY, None),
+            HopError::Network("Error: HTTP 502 Bad Gateway".to_string())
+        );
+        assert_eq!(
+            HopError::from_response(StatusCode::IM_A_TEAPOT, body("deployment_not_found")),
+            HopError::NotFound("message".to_string())
+        );
+    }
+
+    #[test]
+    fn test_exit_code_of() {
+        let error = Err::<(), _>(HopError::Forbidden("no".to_string()))
+            .context("Failed to delete")
+            .unwrap_err();
+
+        assert_eq!(HopError::exit_code_of(&error), 5);
+        assert_eq!(HopError::exit_code_of(&anyhow::anyhow!("other")), 1);
+    }
+}
@@ -1,16 +1,23 @@
 pub(crate) mod commands;
 pub(crate) mod config;
+pub(crate) mod error;
 pub(crate) mod state;
 pub(crate) mod store;
 pub(crate) mod utils;
 
+use std::io::IsTerminal;
+use std::time::Duration;
+
 use anyhow::Result;
 use clap::Parser;
 use commands::update::version_notice;
 #[cfg(feature = "update")]
 use commands::Commands::Update;
 use commands::{handle_command, Commands};
+use error::HopError;
+use state::http::HttpOptions;
 use state::{State, StateOptions};
+use utils::{parse_duration, prompt};
 
 #[derive(Debug, Parser)]
 #[clap(
@@ -31,8 +38,45 @@ pub struct CLI {
     )]
     pub project: Option<String>,
 
+    #[clap(
+        long,
+        help = "Name of the context to use, defaults to the current one",
+        global = true
+    )]
+    pub context: Option<String>,
+
+    #[clap(
+        long,
+        help = "Never prompt, fail when an argument is missing instead. Enabled in CI and when stdin is not a terminal",
+        global = true
+    )]
+    pub no_input: bool,
+
     #[clap(short = 'D', long, help = "Enable debug mode", global = true)]
     pub debug: bool,
+
+    #[clap(
+        long,
+        help = "How many times to retry failed requests, defaults to 3",
+        global = true
+    )]
+    pub retries: Option<u32>,
+
+    #[clap(
+        long,
+        help = "Timeout for connecting to the API, e.g. `10s`",
+        global = true,
+        value_parser = parse_duration
+    )]
+    pub connect_timeout: Option<Duration>,
+
+    #[clap(
+        long,
+        help = "Timeout for each request to the API, e.g. `1m`",
+        global = true,
+        value_parser = parse_duration
+    )]
+    pub request_timeout: Option<Duration>,
 }
 
 pub async fn run() -> Result<()> {
@@ -46,11 +90,32 @@ pub async fn run() -> Result<()> {
 
     utils::sudo::fix().await?;
 
+    // flags take precedence over the environment
+    let mut http = HttpOptions::from_env().unwrap_or_else(|error| exit_with_error(&error));
+
+    if let Some(retries) = cli.retries {
+        http.retries = retries;
+    }
+
+    if let Some(timeout) = cli.connect_timeout {
+        http.connect_timeout = timeout;
+    }
+
+    if let Some(timeout) = cli.request_timeout {
+        http.request_timeout = timeout;
+    }
+
+    // prompts are disabled before anything can ask for input
+    prompt::set_no_input(cli.no_input || State::check_if_ci() || !std::io::stdin().is_terminal());
+
     let state = State::new(StateOptions {
         override_project: std::env::var("PROJECT_ID").ok().or(cli.project),
         override_token: std::env::var("TOKEN").ok(),
+        context: cli.context.or_else(|| std::env::var("HOP_CONTEXT").ok()),
+        http,
     })
-    .await;
+    .await
+    .unwrap_or_else(|error| exit_with_error(&error));
 
     // do not show notice in debug mode or CI
     if !cfg!(not(debug_assertions)) || !state.is_ci {
@@ -63,8 +128,7 @@ pub async fn run() -> Result<()> {
     }
 
     if let Err(error) = handle_command(cli.commands, state).await {
-        log::error!("{}", error);
-        std::process::exit(1);
+        exit_with_error(&error);
     }
 
     utils::clean_term();
@@ -72,6 +136,12 @@ pub async fn run() -> Result<()> {
     Ok(())
 }
 
+/// Logs the error and exits with the code of its kind, see `HopError`.
+fn exit_with_error(error: &anyhow::Error) -> ! {
+    log::error!("{}", error);
+    std::process::exit(HopError::exit_code_of(error));
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
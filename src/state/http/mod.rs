@@ -1,25 +1,94 @@
-mod types;
+pub mod types;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Result};
 use hyper::StatusCode;
-use reqwest::header::HeaderMap;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use reqwest::Client as AsyncClient;
 
 use self::types::{Base, ErrorResponse};
 use crate::config::VERSION;
+use crate::error::HopError;
+use crate::utils::parse_duration;
 
 const HOP_API_BASE_URL: &str = "https://api.hop.io/v1";
 
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpOptions {
+    /// how many times a failed request is retried
+    pub retries: u32,
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl HttpOptions {
+    /// Defaults overridden by `HOP_RETRIES`, `HOP_CONNECT_TIMEOUT`
+    /// and `HOP_REQUEST_TIMEOUT` if they are set.
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Like `from_env`, reading the variables with `var`.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let mut options = Self::default();
+
+        if let Some(retries) = var("HOP_RETRIES") {
+            options.retries = retries
+                .parse()
+                .map_err(|_| anyhow!("Invalid `HOP_RETRIES` value: {retries}"))?;
+        }
+
+        if let Some(timeout) = var("HOP_CONNECT_TIMEOUT") {
+            options.connect_timeout = parse_duration(&timeout)?;
+        }
+
+        if let Some(timeout) = var("HOP_REQUEST_TIMEOUT") {
+            options.request_timeout = parse_duration(&timeout)?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// The url of the API, `API_URL` takes precedence over the one of the context.
+pub fn api_url(context_api_url: Option<String>) -> Option<String> {
+    std::env::var("API_URL").ok().or(context_api_url)
+}
+
 #[derive(Debug, Clone)]
 pub struct HttpClient {
     pub client: AsyncClient,
     pub base_url: String,
     pub headers: HeaderMap,
     pub ua: String,
+    pub options: HttpOptions,
 }
 
 impl HttpClient {
     pub fn new(token: Option<String>, api_url: Option<String>) -> Self {
+        Self::with_options(token, api_url, HttpOptions::default())
+    }
+
+    pub fn with_options(
+        token: Option<String>,
+        api_url: Option<String>,
+        options: HttpOptions,
+    ) -> Self {
         let mut headers = HeaderMap::new();
 
         headers.insert("accept", "application/json".parse().unwrap());
@@ -43,11 +112,13 @@ impl HttpClient {
             client: AsyncClient::builder()
                 .user_agent(ua.clone())
                 .default_headers(headers.clone())
+                .connect_timeout(options.connect_timeout)
                 .build()
                 .unwrap(),
             base_url,
             headers,
             ua,
+            options,
         }
     }
 
@@ -79,18 +150,18 @@ impl HttpClient {
         response: reqwest::Response,
         status: StatusCode,
     ) -> Result<Option<T>> {
-        let body = response.json::<ErrorResponse>().await;
-
-        match body {
-            Ok(body) => Err(anyhow!("{}", body.error.message)),
-            Err(err) => {
-                log::debug!("Error deserialize message: {:#?}", err);
+        let body = response
+            .json::<ErrorResponse>()
+            .await
+            .map_err(|err| log::debug!("Error deserialize message: {:#?}", err))
+            .ok();
 
-                Err(anyhow!("Error: HTTP {:#?}", status))
-            }
-        }
+        Err(HopError::from_response(status, body).into())
     }
 
+    /// Sends a request, retrying on connection errors, `429` and `5xx`
+    /// responses. Only idempotent methods are retried on errors that could
+    /// happen after the server received the request.
     pub async fn request<T>(
         &self,
         method: &str,
@@ -100,36 +171,261 @@ impl HttpClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let mut request = self.client.request(
-            method.parse().unwrap(),
-            format!("{}{}", self.base_url, path),
-        );
-
         log::debug!("request: {} {} {:?}", method, path, data);
 
-        if let Some((body, content_type)) = data {
-            request = request.header("content-type", content_type);
+        let (mut body, content_type) = match data {
+            Some((body, content_type)) => (Some(body), Some(content_type)),
+            None => (None, None),
+        };
+
+        // streamed bodies can't be replayed, so those are only sent once
+        let bytes = body
+            .as_ref()
+            .and_then(|body| body.as_bytes())
+            .map(|bytes| bytes.to_vec());
+        let replayable = body.is_none() || bytes.is_some();
+        let idempotent = matches!(method, "GET" | "HEAD" | "PUT" | "DELETE" | "OPTIONS");
+
+        let mut attempt = 0;
+
+        loop {
+            let mut request = self
+                .client
+                .request(method.parse()?, format!("{}{}", self.base_url, path))
+                .timeout(self.options.request_timeout);
+
+            if let Some(content_type) = content_type {
+                request = request.header("content-type", content_type);
+            }
+
+            if let Some(ref bytes) = bytes {
+                // show body in debug mode / when developing
+                #[cfg(debug_assertions)]
+                log::debug!("request body: {:?}", String::from_utf8_lossy(bytes));
+
+                request = request.body(bytes.clone());
+            } else if let Some(body) = body.take() {
+                request = request.body(body);
+            }
+
+            let request = request.build()?;
+
+            #[cfg(debug_assertions)]
+            let now = tokio::time::Instant::now();
+
+            let result = self.client.execute(request).await;
 
-            // show body in debug mode / when developing
             #[cfg(debug_assertions)]
-            log::debug!(
-                "request body: {:?}",
-                String::from_utf8(body.as_bytes().unwrap().to_vec())?
-            );
+            log::debug!("response in: {:#?}", now.elapsed());
+
+            let delay = match result {
+                Ok(ref response) => match response.status() {
+                    StatusCode::TOO_MANY_REQUESTS => {
+                        Some(retry_after(response).unwrap_or_else(|| backoff(attempt)))
+                    }
+                    status if status.is_server_error() && idempotent => Some(backoff(attempt)),
+                    _ => None,
+                },
+                // connection errors happen before anything was sent
+                Err(ref err) if err.is_connect() || (idempotent && err.is_timeout()) => {
+                    Some(backoff(attempt))
+                }
+                Err(_) => None,
+            };
+
+            match delay {
+                Some(delay) if replayable && attempt < self.options.retries => {
+                    attempt += 1;
+
+                    log::debug!(
+                        "Retrying {method} {path} in {delay:?} ({attempt}/{})",
+                        self.options.retries
+                    );
+
+                    tokio::time::sleep(delay).await;
+                }
 
-            request = request.body(body);
+                _ => return self.handle_response(result.map_err(HopError::from)?).await,
+            }
         }
+    }
+}
+
+/// Exponential backoff with jitter, between half and the full delay.
+fn backoff(attempt: u32) -> Duration {
+    let delay = BASE_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF);
+
+    let jitter = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default() as f64
+        / 1_000_000_000.0;
+
+    delay.mul_f64(0.5 + jitter / 2.0)
+}
+
+/// Only the seconds form of `Retry-After` is used by the API.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(|secs| Duration::from_secs(secs).min(MAX_RETRY_AFTER))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::Infallible;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Response, Server};
+    use serde_json::Value;
+
+    use super::*;
+
+    /// Starts a stub API that answers the nth request with `respond(n)`,
+    /// returns its url and the amount of requests it received.
+    fn stub_server(
+        respond: fn(usize) -> (u16, Option<&'static str>, Duration),
+    ) -> (String, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
 
-        let request = request.build()?;
+        let service = make_service_fn(move |_| {
+            let counter = counter.clone();
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_| {
+                    let hit = counter.fetch_add(1, Ordering::SeqCst);
+                    let (status, retry_after, delay) = respond(hit);
+
+                    async move {
+                        tokio::time::sleep(delay).await;
+
+                        let mut response = Response::builder().status(status);
+
+                        if let Some(retry_after) = retry_after {
+                            response = response.header("retry-after", retry_after);
+                        }
+
+                        Ok::<_, Infallible>(
+                            response
+                                .body(Body::from(r#"{"success":true,"data":{}}"#))
+                                .unwrap(),
+                        )
+                    }
+                }))
+            }
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+        let url = format!("http://{}", server.local_addr());
+
+        tokio::spawn(server);
+
+        (url, hits)
+    }
+
+    fn client(url: String, retries: u32, request_timeout: Duration) -> HttpClient {
+        HttpClient::with_options(
+            None,
+            Some(url),
+            HttpOptions {
+                retries,
+                connect_timeout: Duration::from_secs(1),
+                request_timeout,
+            },
+        )
+    }
 
-        #[cfg(debug_assertions)]
+    #[tokio::test]
+    async fn test_retries_server_errors() {
+        let (url, hits) = stub_server(|hit| {
+            let status = if hit < 2 { 503 } else { 200 };
+
+            (status, None, Duration::ZERO)
+        });
+
+        // configured the way the CLI is, from variables like in the environment
+        let vars = HashMap::from([("HOP_RETRIES", "2")]);
+        let options = HttpOptions::from_vars(|name| vars.get(name).map(|v| v.to_string())).unwrap();
+
+        let http = HttpClient::with_options(None, Some(url.clone()), options);
+        assert_eq!(http.base_url, url);
+        assert_eq!(http.options.retries, 2);
+
+        assert!(http.request::<Value>("GET", "/", None).await.is_ok());
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_retries() {
+        let (url, hits) = stub_server(|_| (500, None, Duration::ZERO));
+
+        let http = client(url, 1, Duration::from_secs(5));
+
+        assert!(http.request::<Value>("GET", "/", None).await.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_non_idempotent() {
+        let (url, hits) = stub_server(|_| (500, None, Duration::ZERO));
+
+        let http = client(url, 3, Duration::from_secs(5));
+
+        let body = Some((b"{}".to_vec().into(), "application/json"));
+
+        assert!(http.request::<Value>("POST", "/", body).await.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_honors_retry_after() {
+        let (url, hits) = stub_server(|hit| {
+            if hit == 0 {
+                (429, Some("1"), Duration::ZERO)
+            } else {
+                (200, None, Duration::ZERO)
+            }
+        });
+
+        let http = client(url, 3, Duration::from_secs(5));
         let now = tokio::time::Instant::now();
 
-        let response = self.client.execute(request).await?;
+        let body = Some((b"{}".to_vec().into(), "application/json"));
+
+        assert!(http.request::<Value>("POST", "/", body).await.is_ok());
+        assert!(now.elapsed() >= Duration::from_secs(1));
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        let (url, hits) = stub_server(|_| (200, None, Duration::from_secs(2)));
+
+        let http = client(url, 0, Duration::from_millis(200));
 
-        #[cfg(debug_assertions)]
-        log::debug!("response in: {:#?}", now.elapsed());
+        assert!(http.request::<Value>("GET", "/", None).await.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_backoff_is_bounded() {
+        for attempt in 0..10 {
+            let delay = backoff(attempt);
 
-        self.handle_response(response).await
+            assert!(delay >= BASE_BACKOFF / 2);
+            assert!(delay <= MAX_BACKOFF);
+        }
     }
 }
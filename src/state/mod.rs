@@ -1,9 +1,10 @@
 pub mod http;
 use anyhow::{ensure, Result};
 
-use self::http::HttpClient;
+use self::http::{api_url, HttpClient, HttpOptions};
 use crate::commands::auth::login::util::{token_options, TokenType};
 use crate::config::EXEC_NAME;
+use crate::error::HopError;
 use crate::store::auth::Auth;
 use crate::store::context::Context;
 
@@ -20,13 +21,18 @@ pub struct State {
 pub struct StateOptions {
     pub override_project: Option<String>,
     pub override_token: Option<String>,
+    pub context: Option<String>,
+    pub http: HttpOptions,
 }
 
 impl State {
-    pub async fn new(options: StateOptions) -> Self {
+    pub async fn new(options: StateOptions) -> Result<Self> {
         // do some logic to get current signed in user
-        let auth = Auth::new().await;
-        let mut ctx = Context::new().await;
+        let auth = Auth::new().await?;
+        let mut ctx = Context::new().await?;
+
+        // a named context replaces the default user, project and api url
+        ctx.use_context(options.context)?;
 
         // override the project id if provided
         ctx.project_override = options
@@ -44,37 +50,40 @@ impl State {
             None
         };
 
-        let (token, token_type) = Self::handle_token(init_token);
+        let (token, token_type) = Self::handle_token(init_token)?;
 
         // preffer the override token over the auth token
-        let http = HttpClient::new(
+        let http = HttpClient::with_options(
             token.clone(),
-            std::env::var("API_URL")
-                .ok()
-                .or_else(|| ctx.override_api_url.clone()),
+            api_url(ctx.override_api_url.clone()),
+            options.http,
         );
 
-        State {
+        Ok(State {
             is_ci: Self::check_if_ci(),
             token_type,
             token,
             http,
             auth,
             ctx,
-        }
+        })
     }
 
     /// Rebuilds the http client with the current auth token.
-    fn handle_token(token: Option<String>) -> (Option<String>, Option<TokenType>) {
+    fn handle_token(token: Option<String>) -> Result<(Option<String>, Option<TokenType>)> {
         let token_type = token
             .as_ref()
-            .map(|token| TokenType::from_token(token).expect("Invalid token type"));
+            .map(|token| {
+                TokenType::from_token(token)
+                    .map_err(|_| HopError::Unauthorized("Invalid token type".to_string()))
+            })
+            .transpose()?;
 
-        (token, token_type)
+        Ok((token, token_type))
     }
 
     /// Checks if the current environment is a CI environment.
-    fn check_if_ci() -> bool {
+    pub fn check_if_ci() -> bool {
         std::env::vars().any(|(key, _)| {
             matches!(
                 key.as_str(),
@@ -93,24 +102,41 @@ impl State {
     pub async fn login(&mut self, token: Option<String>) -> Result<()> {
         ensure!(
             token.is_some() || self.token.is_some(),
-            "You are not logged in. Please run `{} auth login` first.",
-            EXEC_NAME
+            HopError::Unauthorized(format!(
+                "You are not logged in. Please run `{EXEC_NAME} auth login` first."
+            ))
         );
 
         if let Some(token) = token {
-            let (token, token_type) = Self::handle_token(Some(token));
+            let (token, token_type) = Self::handle_token(Some(token))?;
 
             self.token = token.clone();
             self.token_type = token_type;
-            self.http = HttpClient::new(token, self.ctx.override_api_url.clone());
+            self.http = HttpClient::with_options(
+                token,
+                api_url(self.ctx.override_api_url.clone()),
+                self.http.options.clone(),
+            );
         }
 
-        let response = token_options(self.http.clone(), self.token_type.clone()).await;
+        let response = token_options(self.http.clone(), self.token_type.clone()).await?;
 
         if !response.email_verified {
             log::warn!("You need to verify your email address before you can use Hop.")
         }
 
+        // only tokens from the store are tracked, not ones from the environment
+        let tracked =
+            self.token.is_some() && self.auth.authorized.get(&response.id) == self.token.as_ref();
+
+        // the store is only written when the details changed
+        if tracked && self.auth.update_account(&response, self.token_type.clone()) {
+            // its okay for this to fail, the details are only informative
+            if let Err(error) = self.auth.save_accounts().await {
+                log::debug!("Failed to save account details: {error}");
+            }
+        }
+
         // get current user to global
         self.ctx.current = Some(response);
 
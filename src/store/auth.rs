@@ -1,16 +1,76 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tokio::fs::{self, File};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use serde_json::{json, Value};
 
-use super::utils::home_path;
+use super::credentials::{CredentialStore, StoredCredentials};
+use super::utils::{home_path, merge_changes, read_store, update_store, Migration};
+use crate::commands::auth::login::util::TokenType;
+use crate::commands::auth::types::AuthorizedClient;
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+/// How often `last_used` is refreshed, so using a token does not rewrite
+/// the store on every command.
+const LAST_USED_PRECISION: Duration = Duration::from_secs(60 * 60);
+
+const MIGRATIONS: &[Migration] = &[
+    // 0 -> 1: only added the schema version
+    |_| Ok(()),
+    // 1 -> 2: tokens moved behind a credential store
+    |value| {
+        value["store"] = json!({ "type": "plaintext" });
+
+        Ok(())
+    },
+];
+
+/// What is known about an account, kept next to the tokens
+/// but never in the credential store since it is not secret.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct Account {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<TokenType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Auth {
     pub authorized: HashMap<String, String>,
+    pub accounts: HashMap<String, Account>,
+    pub store: CredentialStore,
+    /// kept so an encrypted store is only unlocked once
+    passphrase: Option<String>,
+    /// the tokens and accounts as they were read, so saving only writes what changed
+    stored: Value,
+}
+
+/// The part of the auth store that is merged with changes of other processes.
+#[derive(Debug, Serialize, Deserialize)]
+struct Tracked {
+    authorized: HashMap<String, String>,
+    accounts: HashMap<String, Account>,
+}
+
+/// What is written to the auth store, the tokens are only
+/// present if the credential store keeps them in the file.
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct AuthFile {
+    #[serde(default)]
+    store: CredentialStore,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    accounts: HashMap<String, Account>,
+    #[serde(flatten)]
+    credentials: StoredCredentials,
 }
 
 impl Auth {
@@ -18,49 +78,154 @@ impl Auth {
         home_path(".hop/auth.json")
     }
 
-    pub async fn new() -> Self {
-        let path = Auth::path();
-
-        match fs::metadata(path.clone()).await {
-            Ok(_) => match File::open(path).await {
-                Ok(mut file) => {
-                    let mut buffer = String::new();
-                    file.read_to_string(&mut buffer)
-                        .await
-                        .expect("Failed to read auth store");
-
-                    serde_json::from_str(&buffer).unwrap()
-                }
-
-                Err(err) => {
-                    panic!("Error opening auth file: {err}")
-                }
-            },
-            Err(_) => Self::default().save().await.unwrap(),
+    pub async fn new() -> Result<Self> {
+        match read_store::<AuthFile>(&Self::path(), MIGRATIONS).await? {
+            Some(file) => {
+                let mut passphrase = None;
+
+                let authorized = file.store.load(&file.credentials, &mut passphrase).await?;
+
+                let mut auth = Self {
+                    authorized,
+                    accounts: file.accounts,
+                    store: file.store,
+                    passphrase,
+                    stored: Value::Null,
+                };
+                auth.stored = auth.tracked()?;
+
+                Ok(auth)
+            }
+
+            None => {
+                let mut auth = Self::default();
+                auth.stored = auth.tracked()?;
+
+                auth.save().await
+            }
         }
     }
 
-    pub async fn save(&self) -> Result<Self> {
+    fn tracked(&self) -> Result<Value> {
+        Ok(json!({
+            "authorized": self.authorized,
+            "accounts": self.accounts,
+        }))
+    }
+
+    pub async fn save(&mut self) -> Result<Self> {
+        let passphrase = self.passphrase.clone();
+
+        self.save_from(passphrase).await
+    }
+
+    /// Writes the changes of this process on top of the store as it is now,
+    /// `file_passphrase` unlocks the file if it uses another credential store.
+    async fn save_from(&mut self, mut file_passphrase: Option<String>) -> Result<Self> {
         let path = Self::path();
 
-        fs::create_dir_all(path.parent().unwrap())
-            .await
-            .expect("Failed to create auth store directory");
+        let base = self.stored.clone();
+        let ours = self.tracked()?;
+        let store = self.store.clone();
+        let passphrase = &mut self.passphrase;
+        let file_passphrase = &mut file_passphrase;
+        let mut merged = None;
+        let merged_out = &mut merged;
+
+        update_store(&path, MIGRATIONS, |mut file: AuthFile| async move {
+            let current = if file.store == store {
+                file.store.load(&file.credentials, passphrase).await?
+            } else {
+                file.store.load(&file.credentials, file_passphrase).await?
+            };
 
-        let mut file = File::create(path.clone())
-            .await
-            .expect("Error opening auth file:");
+            let mut theirs = json!({
+                "authorized": current,
+                "accounts": file.accounts,
+            });
+            merge_changes(&base, &ours, &mut theirs);
 
-        file.write_all(
-            serde_json::to_string(&self)
-                .expect("Failed to deserialize auth")
-                .as_bytes(),
-        )
-        .await
-        .expect("Failed to write auth store");
+            let tracked = serde_json::from_value::<Tracked>(theirs)?;
 
-        log::debug!("Saved credentials to {}", path.display());
+            file.credentials = store.save(&tracked.authorized, passphrase).await?;
+            file.accounts = tracked.accounts.clone();
+            file.store = store;
+            *merged_out = Some(tracked);
+
+            Ok(file)
+        })
+        .await?;
+
+        if let Some(merged) = merged {
+            self.authorized = merged.authorized;
+            self.accounts = merged.accounts;
+            self.stored = self.tracked()?;
+        }
+
+        log::debug!("Saved credentials to {} ({})", path.display(), self.store);
 
         Ok(self.clone())
     }
+
+    /// Refreshes the details of an account after its token was used,
+    /// returns whether anything changed.
+    pub fn update_account(
+        &mut self,
+        client: &AuthorizedClient,
+        token_type: Option<TokenType>,
+    ) -> bool {
+        let account = self.accounts.entry(client.id.clone()).or_default();
+        let previous = account.clone();
+
+        account.name = Some(client.name.clone());
+        // project tokens do not belong to a user so they have no email
+        account.email = match token_type {
+            Some(TokenType::Ptk) => None,
+            _ => Some(client.email.clone()),
+        };
+        account.token_type = token_type;
+
+        let now = Utc::now();
+
+        if account
+            .last_used
+            .and_then(|last_used| (now - last_used).to_std().ok())
+            .is_none_or(|elapsed| elapsed >= LAST_USED_PRECISION)
+        {
+            account.last_used = Some(now);
+        }
+
+        *account != previous
+    }
+
+    /// Writes only the account details, without touching the tokens
+    /// so the credential store does not need to be unlocked.
+    pub async fn save_accounts(&self) -> Result<()> {
+        let accounts = self
+            .accounts
+            .iter()
+            .filter(|(id, _)| self.authorized.contains_key(*id))
+            .map(|(id, account)| (id.clone(), account.clone()))
+            .collect::<Vec<_>>();
+
+        update_store(&Self::path(), MIGRATIONS, |mut file: AuthFile| async move {
+            file.accounts.extend(accounts);
+
+            Ok(file)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Moves all tokens to another credential store.
+    pub async fn migrate_store(&mut self, store: CredentialStore) -> Result<()> {
+        let previous = std::mem::replace(&mut self.store, store);
+        let previous_passphrase = self.passphrase.take();
+
+        self.save_from(previous_passphrase).await?;
+
+        // only erase once the tokens are safely in the new store
+        previous.erase().await
+    }
 }
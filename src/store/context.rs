@@ -1,14 +1,38 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
+use leap_client_rs::LeapOptions;
 use serde::{Deserialize, Serialize};
-use tokio::fs::{self, File};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use serde_json::Value;
 
-use super::utils::home_path;
+use super::utils::{home_path, read_store, save_store, Migration};
 use crate::commands::auth::types::AuthorizedClient;
 use crate::commands::projects::types::Project;
-use crate::config::EXEC_NAME;
+use crate::config::{EXEC_NAME, HOP_BUILD_BASE_URL, LEAP_PROJECT};
+use crate::error::HopError;
+
+const MIGRATIONS: &[Migration] = &[
+    // 0 -> 1: only added the schema version
+    |_| Ok(()),
+];
+
+/// A named set of settings that replace the top level ones when in use.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct NamedContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub builder_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leap_project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leap_ws_url: Option<String>,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Context {
@@ -21,6 +45,12 @@ pub struct Context {
     pub override_api_url: Option<String>,
     // latest version of the cli and time it was last checked
     pub last_version_check: Option<(String, String)>,
+    /// named contexts, by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contexts: BTreeMap<String, NamedContext>,
+    /// named context used when none is passed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_context: Option<String>,
 
     /// runtime context
     #[serde(skip)]
@@ -28,6 +58,15 @@ pub struct Context {
     /// runtime context
     #[serde(skip)]
     pub project_override: Option<String>,
+    /// runtime context, name of the named context in use
+    #[serde(skip)]
+    pub active_context: Option<String>,
+    /// runtime context, top level settings hidden by the named context
+    #[serde(skip)]
+    top_level: NamedContext,
+    /// runtime context, the store as it was read so saving only writes what changed
+    #[serde(skip)]
+    stored: Value,
 }
 
 impl Context {
@@ -58,32 +97,106 @@ impl Context {
         }
     }
 
-    pub fn current_project_error(self) -> Project {
-        self.current_project().unwrap_or_else(|| panic!("No project specified, run `{EXEC_NAME} projects switch` or use --project to specify a project"))
+    pub fn current_project_error(self) -> Result<Project> {
+        self.current_project().ok_or_else(|| {
+            HopError::Local(format!("No project specified, run `{EXEC_NAME} projects switch` or use --project to specify a project")).into()
+        })
     }
 
-    pub async fn new() -> Self {
-        let path = Self::path();
+    pub async fn new() -> Result<Self> {
+        match read_store::<Self>(&Self::path(), MIGRATIONS).await? {
+            Some(mut ctx) => {
+                ctx.stored = serde_json::to_value(&ctx)?;
 
-        match fs::metadata(path.clone()).await {
-            Ok(_) => match File::open(path).await {
-                Ok(mut file) => {
-                    let mut buffer = String::new();
-                    file.read_to_string(&mut buffer)
-                        .await
-                        .expect("Failed to read auth store");
-
-                    serde_json::from_str(&buffer).unwrap()
-                }
-
-                Err(err) => {
-                    panic!("Error opening auth file: {err}")
-                }
-            },
-            Err(_) => Self::default().save().await.unwrap(),
+                Ok(ctx)
+            }
+
+            None => {
+                let mut ctx = Self::default();
+                ctx.stored = serde_json::to_value(&ctx)?;
+
+                ctx.save().await
+            }
         }
     }
 
+    /// Applies a named context over the top level settings, `name`
+    /// defaults to the current context.
+    pub fn use_context(&mut self, name: Option<String>) -> Result<()> {
+        let Some(name) = name.or_else(|| self.current_context.clone()) else {
+            return Ok(());
+        };
+
+        let named = self.contexts.get(&name).cloned().ok_or_else(|| {
+            HopError::Local(format!(
+                "Context `{name}` does not exist, run `{EXEC_NAME} context ls` to see all contexts"
+            ))
+        })?;
+
+        self.top_level = NamedContext {
+            user: self.default_user.take(),
+            project: self.default_project.take(),
+            api_url: self.override_api_url.take(),
+            ..Default::default()
+        };
+
+        self.default_user = named.user;
+        self.default_project = named.project;
+        self.override_api_url = named.api_url;
+        self.active_context = Some(name);
+
+        Ok(())
+    }
+
+    /// Stops using the named context in use, restoring the top level settings.
+    pub fn leave_context(&mut self) {
+        if self.active_context.take().is_none() {
+            return;
+        }
+
+        let top_level = std::mem::take(&mut self.top_level);
+
+        self.default_user = top_level.user;
+        self.default_project = top_level.project;
+        self.override_api_url = top_level.api_url;
+    }
+
+    pub fn active_named_context(&self) -> Option<&NamedContext> {
+        self.active_context
+            .as_ref()
+            .and_then(|name| self.contexts.get(name))
+    }
+
+    pub fn builder_url(&self) -> String {
+        std::env::var("BUILDER_URL")
+            .ok()
+            .or_else(|| self.active_named_context()?.builder_url.clone())
+            .unwrap_or_else(|| HOP_BUILD_BASE_URL.to_string())
+    }
+
+    pub fn leap_project(&self) -> String {
+        std::env::var("LEAP_PROJECT")
+            .ok()
+            .or_else(|| self.active_named_context()?.leap_project.clone())
+            .unwrap_or_else(|| LEAP_PROJECT.to_string())
+    }
+
+    pub fn leap_ws_url(&self) -> String {
+        std::env::var("LEAP_WS_URL")
+            .ok()
+            .or_else(|| self.active_named_context()?.leap_ws_url.clone())
+            .unwrap_or_else(|| LeapOptions::default().ws_url.to_string())
+    }
+
+    pub fn validate_context_name(name: &str) -> Result<()> {
+        ensure!(
+            !name.is_empty() && !name.contains(char::is_whitespace),
+            "Context names must not be empty or contain whitespace"
+        );
+
+        Ok(())
+    }
+
     pub async fn save(&mut self) -> Result<Self> {
         if let Some(ref authorized) = self.current {
             self.default_user = Some(authorized.id.clone());
@@ -91,21 +204,33 @@ impl Context {
 
         let path = Self::path();
 
-        fs::create_dir_all(path.parent().unwrap())
-            .await
-            .expect("Failed to create auth store directory");
+        // switching users or projects inside a named context only changes that context
+        let mut stored = self.clone();
 
-        let mut file = File::create(path.clone())
-            .await
-            .expect("Error opening auth file:");
+        if let Some(ref name) = self.active_context {
+            if let Some(named) = stored.contexts.get_mut(name) {
+                named.user = self.default_user.clone();
+                named.project = self.default_project.clone();
+                named.api_url = self.override_api_url.clone();
+            }
+
+            stored.default_user = self.top_level.user.clone();
+            stored.default_project = self.top_level.project.clone();
+            stored.override_api_url = self.top_level.api_url.clone();
+        }
+
+        // other processes might have saved since this one read the store
+        let mut merged = save_store(&path, MIGRATIONS, &self.stored, &stored).await?;
+
+        merged.stored = serde_json::to_value(&merged)?;
+        merged.current = self.current.take();
+        merged.project_override = self.project_override.take();
+
+        if let Some(name) = self.active_context.take() {
+            merged.use_context(Some(name))?;
+        }
 
-        file.write_all(
-            serde_json::to_string(&self)
-                .expect("Failed to deserialize auth")
-                .as_bytes(),
-        )
-        .await
-        .expect("Failed to write auth store");
+        *self = merged;
 
         log::debug!("Saved context to {}", path.display());
 
@@ -118,3 +243,64 @@ impl Context {
         format!("{EXEC_NAME} update")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_context() {
+        let mut ctx = Context {
+            default_project: Some("top".to_string()),
+            contexts: BTreeMap::from([(
+                "staging".to_string(),
+                NamedContext {
+                    project: Some("staging-project".to_string()),
+                    api_url: Some("https://staging.example.com".to_string()),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+
+        assert!(ctx.clone().use_context(Some("prod".to_string())).is_err());
+
+        ctx.use_context(Some("staging".to_string())).unwrap();
+
+        assert_eq!(ctx.default_project.as_deref(), Some("staging-project"));
+        assert_eq!(
+            ctx.override_api_url.as_deref(),
+            Some("https://staging.example.com")
+        );
+        assert_eq!(ctx.top_level.project.as_deref(), Some("top"));
+    }
+
+    #[test]
+    fn test_leave_context() {
+        let mut ctx = Context {
+            default_project: Some("top".to_string()),
+            contexts: BTreeMap::from([(
+                "staging".to_string(),
+                NamedContext {
+                    project: Some("staging-project".to_string()),
+                    api_url: Some("https://staging.example.com".to_string()),
+                    ..Default::default()
+                },
+            )]),
+            current_context: Some("staging".to_string()),
+            ..Default::default()
+        };
+
+        ctx.use_context(None).unwrap();
+
+        // what deleting the context in use does
+        ctx.contexts.remove("staging");
+        ctx.current_context = None;
+        ctx.leave_context();
+
+        assert_eq!(ctx.active_context, None);
+        assert_eq!(ctx.default_project.as_deref(), Some("top"));
+        assert_eq!(ctx.override_api_url, None);
+        assert!(ctx.clone().use_context(None).is_ok());
+    }
+}
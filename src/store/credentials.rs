@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::num::NonZeroU32;
+use std::process::Stdio;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::error::HopError;
+use crate::utils::prompt::{self, Arg};
+
+pub const CREDENTIALS_KEY_ENV: &str = "HOP_CREDENTIALS_KEY";
+
+const PBKDF2_ITERATIONS: u32 = 100_000;
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+/// Where the tokens in the auth store are kept.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CredentialStore {
+    /// in the auth store as is
+    #[default]
+    Plaintext,
+    /// in the auth store, encrypted with a passphrase
+    Encrypted,
+    /// handed to an external command, see `run_helper`
+    Helper { command: String },
+}
+
+impl CredentialStore {
+    pub fn values() -> Vec<&'static str> {
+        vec!["plaintext", "encrypted", "helper"]
+    }
+
+    pub async fn load(
+        &self,
+        stored: &StoredCredentials,
+        passphrase: &mut Option<String>,
+    ) -> Result<HashMap<String, String>> {
+        match self {
+            Self::Plaintext => Ok(stored.authorized.clone()),
+
+            Self::Encrypted => match stored.encrypted {
+                Some(ref encrypted) => {
+                    let key = get_passphrase(passphrase, false)?;
+
+                    decrypt(encrypted, &key)
+                }
+
+                None => Ok(HashMap::new()),
+            },
+
+            Self::Helper { command } => Ok(run_helper(command, "get", None)
+                .await?
+                .unwrap_or_default()
+                .tokens),
+        }
+    }
+
+    pub async fn save(
+        &self,
+        tokens: &HashMap<String, String>,
+        passphrase: &mut Option<String>,
+    ) -> Result<StoredCredentials> {
+        match self {
+            Self::Plaintext => Ok(StoredCredentials {
+                authorized: tokens.clone(),
+                encrypted: None,
+            }),
+
+            Self::Encrypted => {
+                let key = get_passphrase(passphrase, true)?;
+
+                Ok(StoredCredentials {
+                    authorized: HashMap::new(),
+                    encrypted: Some(encrypt(tokens, &key)?),
+                })
+            }
+
+            Self::Helper { command } => {
+                let payload = HelperPayload {
+                    tokens: tokens.clone(),
+                };
+
+                run_helper(command, "store", Some(&payload)).await?;
+
+                Ok(StoredCredentials::default())
+            }
+        }
+    }
+
+    /// Removes the tokens from the backend when moving away from it.
+    pub async fn erase(&self) -> Result<()> {
+        if let Self::Helper { command } = self {
+            run_helper(command, "erase", None).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for CredentialStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plaintext => write!(f, "plaintext"),
+            Self::Encrypted => write!(f, "encrypted"),
+            Self::Helper { command } => write!(f, "helper (`{command}`)"),
+        }
+    }
+}
+
+/// Parses `plaintext`, `encrypted` or `helper:<command>`.
+impl FromStr for CredentialStore {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once(':') {
+            Some(("helper", command)) if !command.trim().is_empty() => Ok(Self::Helper {
+                command: command.trim().to_string(),
+            }),
+            None if s == "plaintext" => Ok(Self::Plaintext),
+            None if s == "encrypted" => Ok(Self::Encrypted),
+            None if s == "helper" => bail!("The helper needs a command, e.g. `helper:my-vault`"),
+            _ => bail!(
+                "Unknown credential store `{s}`, use one of: {}",
+                Self::values().join(", ")
+            ),
+        }
+    }
+}
+
+/// The part of the auth store that holds the tokens.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StoredCredentials {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub authorized: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted: Option<EncryptedCredentials>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EncryptedCredentials {
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+/// What is exchanged with a credential helper over stdin/stdout.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct HelperPayload {
+    pub tokens: HashMap<String, String>,
+}
+
+fn get_passphrase(cached: &mut Option<String>, confirm: bool) -> Result<String> {
+    if let Ok(key) = std::env::var(CREDENTIALS_KEY_ENV) {
+        return Ok(key);
+    }
+
+    if let Some(ref passphrase) = cached {
+        return Ok(passphrase.clone());
+    }
+
+    ensure!(
+        prompt::is_interactive(),
+        HopError::Unauthorized(format!(
+            "The credential store is encrypted, set `{CREDENTIALS_KEY_ENV}` to unlock it"
+        ))
+    );
+
+    let mut prompt = prompt::password(
+        "Credential store passphrase",
+        Arg::new("passphrase", CREDENTIALS_KEY_ENV),
+    )?;
+
+    if confirm {
+        prompt.with_confirmation("Confirm passphrase", "Passphrases do not match");
+    }
+
+    let passphrase = prompt.interact()?;
+
+    *cached = Some(passphrase.clone());
+
+    Ok(passphrase)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<LessSafeKey> {
+    let mut key = [0u8; KEY_LEN];
+
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(PBKDF2_ITERATIONS).unwrap(),
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+
+    let key = UnboundKey::new(&CHACHA20_POLY1305, &key)
+        .map_err(|_| anyhow!("Failed to create encryption key"))?;
+
+    Ok(LessSafeKey::new(key))
+}
+
+fn encrypt(tokens: &HashMap<String, String>, passphrase: &str) -> Result<EncryptedCredentials> {
+    let rng = SystemRandom::new();
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut salt)
+        .and_then(|_| rng.fill(&mut nonce))
+        .map_err(|_| anyhow!("Failed to generate random bytes"))?;
+
+    let mut data = serde_json::to_vec(tokens)?;
+
+    derive_key(passphrase, &salt)?
+        .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Failed to encrypt credentials"))?;
+
+    Ok(EncryptedCredentials {
+        salt: BASE64.encode(salt),
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(data),
+    })
+}
+
+fn decrypt(encrypted: &EncryptedCredentials, passphrase: &str) -> Result<HashMap<String, String>> {
+    let salt = BASE64.decode(&encrypted.salt)?;
+    let nonce = BASE64.decode(&encrypted.nonce)?;
+    let mut data = BASE64.decode(&encrypted.ciphertext)?;
+
+    let nonce = Nonce::try_assume_unique_for_key(&nonce)
+        .map_err(|_| anyhow!("Invalid nonce in the credential store"))?;
+
+    let plaintext = derive_key(passphrase, &salt)?
+        .open_in_place(nonce, Aad::empty(), &mut data)
+        .map_err(|_| {
+            HopError::Unauthorized("Wrong passphrase for the credential store".to_string())
+        })?;
+
+    Ok(serde_json::from_slice(plaintext)?)
+}
+
+/// Runs `<command> <action>` through the shell, like git credential helpers.
+///
+/// - `get` prints `{"tokens": {"<user id>": "<token>"}}` to stdout
+/// - `store` reads the same JSON from stdin
+/// - `erase` removes every stored token
+async fn run_helper(
+    command: &str,
+    action: &str,
+    input: Option<&HelperPayload>,
+) -> Result<Option<HelperPayload>> {
+    let line = format!("{command} {action}");
+
+    #[cfg(not(windows))]
+    let mut child = Command::new("sh");
+    #[cfg(not(windows))]
+    child.arg("-c").arg(&line);
+
+    #[cfg(windows)]
+    let mut child = Command::new("cmd");
+    #[cfg(windows)]
+    child.arg("/C").arg(&line);
+
+    let mut child = child
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .with_context(|| format!("Failed to run credential helper `{command}`"))?;
+
+    // dropping stdin closes it so the helper knows the input is done
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Some(input) = input {
+            stdin.write_all(&serde_json::to_vec(input)?).await?;
+        }
+    }
+
+    let output = child.wait_with_output().await?;
+
+    ensure!(
+        output.status.success(),
+        HopError::Local(format!(
+            "Credential helper `{line}` failed with {}",
+            output.status
+        ))
+    );
+
+    if output.stdout.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(&output.stdout)
+        .map(Some)
+        .with_context(|| format!("Invalid output from credential helper `{line}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encryption_roundtrip() {
+        let tokens = HashMap::from([("user_1".to_string(), "pat_123".to_string())]);
+
+        let encrypted = encrypt(&tokens, "hunter2").unwrap();
+
+        assert!(!encrypted.ciphertext.contains("pat_123"));
+        assert_eq!(decrypt(&encrypted, "hunter2").unwrap(), tokens);
+        assert!(decrypt(&encrypted, "hunter3").is_err());
+    }
+
+    #[test]
+    fn test_parse_store() {
+        assert_eq!(
+            "helper:vault-hop".parse::<CredentialStore>().unwrap(),
+            CredentialStore::Helper {
+                command: "vault-hop".to_string()
+            }
+        );
+        assert!("helper".parse::<CredentialStore>().is_err());
+        assert!("keychain".parse::<CredentialStore>().is_err());
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn test_helper_protocol() {
+        let file = std::env::temp_dir().join(format!("hop-helper-{}", std::process::id()));
+        let store = CredentialStore::Helper {
+            command: format!(
+                "f={}; h() {{ case $1 in get) cat $f 2>/dev/null || true;; store) cat > $f;; erase) rm -f $f;; esac; }}; h",
+                file.display()
+            ),
+        };
+
+        let tokens = HashMap::from([("user_1".to_string(), "pat_123".to_string())]);
+
+        store.save(&tokens, &mut None).await.unwrap();
+
+        let stored = StoredCredentials::default();
+        assert_eq!(store.load(&stored, &mut None).await.unwrap(), tokens);
+
+        store.erase().await.unwrap();
+        assert!(store.load(&stored, &mut None).await.unwrap().is_empty());
+    }
+}
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use tokio::fs::{self, File};
 use tokio::io::AsyncWriteExt;
 
+use crate::commands::ignite::types::Autoscaling;
+
 pub static VALID_HOP_FILENAMES: &[&str] = &[
     "hop.yml",
     "hop.yaml",
@@ -28,6 +30,8 @@ pub struct HopFile {
 pub struct HopFileConfig {
     pub project_id: String,
     pub deployment_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autoscaling: Option<Autoscaling>,
 }
 
 impl HopFile {
@@ -37,6 +41,7 @@ impl HopFile {
             config: HopFileConfig {
                 project_id: project.to_string(),
                 deployment_id: deployment.to_string(),
+                autoscaling: None,
             },
             path,
         }
@@ -1,4 +1,5 @@
 pub mod auth;
 pub mod context;
+pub mod credentials;
 pub mod hopfile;
 pub mod utils;
@@ -1,4 +1,28 @@
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use fs2::FileExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+
+use crate::error::HopError;
+
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Upgrades a store from the version at its index in the list to the next one.
+/// The schema version of a store is the amount of its migrations, add one
+/// when its format changes.
+pub type Migration = fn(&mut Value) -> Result<()>;
 
 pub fn home_path(to_join: &str) -> PathBuf {
     let path = dirs::home_dir()
@@ -9,3 +33,438 @@ pub fn home_path(to_join: &str) -> PathBuf {
 
     path
 }
+
+/// Advisory lock on a store, released when dropped.
+pub struct StoreLock {
+    file: std::fs::File,
+}
+
+impl Drop for StoreLock {
+    fn drop(&mut self) {
+        self.file.unlock().ok();
+    }
+}
+
+/// Locks `<path>.lock` so concurrent `hop` processes don't interleave
+/// reads and writes of the same store.
+pub async fn lock(path: &Path) -> Result<StoreLock> {
+    let lock_path = with_suffix(path, "lock");
+
+    if let Some(parent) = lock_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .context("Failed to create store directory")?;
+    }
+
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .with_context(|| format!("Failed to open lock file {}", lock_path.display()))?;
+
+    let started = tokio::time::Instant::now();
+
+    while file.try_lock_exclusive().is_err() {
+        if started.elapsed() >= LOCK_TIMEOUT {
+            return Err(HopError::Local(format!(
+                "Timed out waiting for another process to release {}",
+                lock_path.display()
+            ))
+            .into());
+        }
+
+        tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+    }
+
+    Ok(StoreLock { file })
+}
+
+/// Reads a store, migrating it to the current schema. A corrupt file is
+/// backed up and replaced by the default value instead of failing.
+/// Returns `None` if the store does not exist yet.
+pub async fn read_store<T>(path: &Path, migrations: &[Migration]) -> Result<Option<T>>
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let _lock = lock(path).await?;
+
+    read_unlocked(path, migrations).await
+}
+
+/// Reads, changes and writes a store while holding its lock, so changes
+/// made by other processes in between are not lost.
+pub async fn update_store<T, F, Fut>(path: &Path, migrations: &[Migration], update: F) -> Result<T>
+where
+    T: Serialize + DeserializeOwned + Default,
+    F: FnOnce(T) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let _lock = lock(path).await?;
+
+    let store = read_unlocked(path, migrations).await?.unwrap_or_default();
+
+    let store = update(store).await?;
+
+    write_unlocked(path, &store, migrations).await?;
+
+    Ok(store)
+}
+
+/// Writes the changes made since the store was read as `base`, on top of
+/// the store as it is now, so changes of other processes are kept.
+pub async fn save_store<T>(
+    path: &Path,
+    migrations: &[Migration],
+    base: &Value,
+    store: &T,
+) -> Result<T>
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let ours = serde_json::to_value(store)?;
+
+    update_store(path, migrations, |current: T| async move {
+        let mut theirs = serde_json::to_value(current)?;
+
+        merge_changes(base, &ours, &mut theirs);
+
+        Ok(serde_json::from_value(theirs)?)
+    })
+    .await
+}
+
+/// Applies what changed from `base` to `ours` on `theirs`, objects are
+/// merged key by key so keys only `theirs` changed are left alone. A
+/// missing or null `base` counts as an empty object.
+pub fn merge_changes(base: &Value, ours: &Value, theirs: &mut Value) {
+    if base == ours {
+        return;
+    }
+
+    let empty = Map::new();
+
+    let base = match base {
+        Value::Object(base) => Some(base),
+        Value::Null => Some(&empty),
+        _ => None,
+    };
+
+    match (base, ours, theirs) {
+        (Some(base), Value::Object(ours), Value::Object(theirs)) => {
+            let keys = base.keys().chain(ours.keys()).collect::<BTreeSet<_>>();
+
+            for key in keys {
+                match (base.get(key), ours.get(key)) {
+                    (base, ours) if base == ours => {}
+
+                    (_, None) => {
+                        theirs.remove(key);
+                    }
+
+                    (base, Some(ours)) => match theirs.get_mut(key) {
+                        Some(theirs) => merge_changes(base.unwrap_or(&Value::Null), ours, theirs),
+
+                        None => {
+                            theirs.insert(key.clone(), ours.clone());
+                        }
+                    },
+                }
+            }
+        }
+
+        (_, ours, theirs) => *theirs = ours.clone(),
+    }
+}
+
+async fn read_unlocked<T>(path: &Path, migrations: &[Migration]) -> Result<Option<T>>
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let content = match fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(
+                HopError::Local(format!("Failed to read store {}: {err}", path.display())).into(),
+            )
+        }
+    };
+
+    match parse_store(&content, migrations) {
+        Ok((store, migrated)) => {
+            if migrated {
+                log::debug!(
+                    "Migrated {} to version {}",
+                    path.display(),
+                    migrations.len()
+                );
+
+                write_unlocked(path, &store, migrations).await?;
+            }
+
+            Ok(Some(store))
+        }
+
+        Err(err) if err.downcast_ref::<HopError>().is_some() => Err(err),
+
+        Err(err) => {
+            let backup = with_suffix(path, &format!("{}.bak", Utc::now().timestamp()));
+
+            fs::rename(path, &backup).await?;
+
+            log::warn!(
+                "{} is corrupt ({err}), it was moved to {} and reset",
+                path.display(),
+                backup.display()
+            );
+
+            let store = T::default();
+
+            write_unlocked(path, &store, migrations).await?;
+
+            Ok(Some(store))
+        }
+    }
+}
+
+fn parse_store<T>(content: &str, migrations: &[Migration]) -> Result<(T, bool)>
+where
+    T: DeserializeOwned,
+{
+    let mut value = serde_json::from_str::<Value>(content)?;
+    let schema_version = migrations.len() as u64;
+
+    // files from before versioning have no version
+    let version = value
+        .get(SCHEMA_VERSION_KEY)
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    if version > schema_version {
+        return Err(HopError::Local(format!(
+            "Store was written by a newer version of the CLI (schema {version}), please update"
+        ))
+        .into());
+    }
+
+    for migration in migrations.iter().skip(version as usize) {
+        migration(&mut value)?;
+    }
+
+    Ok((serde_json::from_value(value)?, version < schema_version))
+}
+
+/// Atomically replaces the store by writing to a temporary file first.
+async fn write_unlocked<T>(path: &Path, store: &T, migrations: &[Migration]) -> Result<()>
+where
+    T: Serialize,
+{
+    let mut value = serde_json::to_value(store)?;
+
+    if let Value::Object(ref mut map) = value {
+        map.insert(SCHEMA_VERSION_KEY.to_string(), migrations.len().into());
+    }
+
+    let tmp = with_suffix(path, "tmp");
+
+    // a leftover from an interrupted write might have other permissions
+    fs::remove_file(&tmp).await.ok();
+
+    let mut options = OpenOptions::new();
+    options.create(true).write(true).truncate(true);
+
+    // stores can contain tokens so only the owner can read them
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let mut file = options
+        .open(&tmp)
+        .await
+        .with_context(|| format!("Failed to open {}", tmp.display()))?;
+
+    file.write_all(serde_json::to_string(&value)?.as_bytes())
+        .await
+        .with_context(|| format!("Failed to write {}", tmp.display()))?;
+    file.sync_all().await?;
+
+    fs::rename(&tmp, path)
+        .await
+        .with_context(|| format!("Failed to replace {}", path.display()))?;
+
+    Ok(())
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(suffix);
+
+    path.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize, Default, PartialEq)]
+    struct TestStore {
+        values: HashMap<String, String>,
+    }
+
+    fn temp_store(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hop-store-{name}-{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+
+        dir.join("store.json")
+    }
+
+    async fn write(path: &Path, store: TestStore) {
+        update_store(path, &[], |_: TestStore| async { Ok(store) })
+            .await
+            .unwrap();
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read() {
+        let path = temp_store("roundtrip");
+
+        write(
+            &path,
+            TestStore {
+                values: values(&[("a", "b")]),
+            },
+        )
+        .await;
+
+        assert_eq!(
+            read_store::<TestStore>(&path, &[]).await.unwrap(),
+            Some(TestStore {
+                values: values(&[("a", "b")]),
+            })
+        );
+        assert!(!with_suffix(&path, "tmp").exists());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recovers_corrupt_store() {
+        let path = temp_store("corrupt");
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{\"values\": {").unwrap();
+
+        let store = read_store::<TestStore>(&path, &[]).await.unwrap();
+
+        assert_eq!(store, Some(TestStore::default()));
+
+        let backups = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .filter(|entry| {
+                let name = entry.as_ref().unwrap().file_name();
+
+                name.to_string_lossy().ends_with(".bak")
+            })
+            .count();
+
+        assert_eq!(backups, 1);
+    }
+
+    #[tokio::test]
+    async fn test_migrates_unversioned_store() {
+        let path = temp_store("migrate");
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{\"old\": {\"a\": \"b\"}}").unwrap();
+
+        let migrations: &[Migration] = &[|value| {
+            let old = value["old"].take();
+            value["values"] = old;
+
+            Ok(())
+        }];
+
+        let store = read_store::<TestStore>(&path, migrations)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(store.values.get("a").map(String::as_str), Some("b"));
+
+        let written =
+            serde_json::from_str::<Value>(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+        assert_eq!(written[SCHEMA_VERSION_KEY], 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_concurrent_saves() {
+        let path = temp_store("concurrent");
+
+        write(
+            &path,
+            TestStore {
+                values: values(&[("a", "1"), ("b", "1")]),
+            },
+        )
+        .await;
+
+        // both processes read the store before either of them saves
+        let base = read_store::<TestStore>(&path, &[]).await.unwrap().unwrap();
+        let base = serde_json::to_value(&base).unwrap();
+
+        let save = |changes: &'static [(&'static str, &'static str)]| {
+            let path = path.clone();
+            let base = base.clone();
+
+            tokio::spawn(async move {
+                let mut store = serde_json::from_value::<TestStore>(base.clone()).unwrap();
+                store.values.extend(values(changes));
+
+                save_store(&path, &[], &base, &store).await.unwrap();
+            })
+        };
+
+        let (first, second) = tokio::join!(save(&[("a", "2")]), save(&[("c", "3")]));
+        first.unwrap();
+        second.unwrap();
+
+        let store = read_store::<TestStore>(&path, &[]).await.unwrap().unwrap();
+
+        assert_eq!(store.values, values(&[("a", "2"), ("b", "1"), ("c", "3")]));
+    }
+
+    #[test]
+    fn test_merge_changes() {
+        let base = serde_json::json!({ "a": 1, "b": { "x": 1, "y": 1 }, "c": 1 });
+        let ours = serde_json::json!({ "a": 2, "b": { "x": 2, "y": 1 }, "e": { "x": 1 } });
+        let mut theirs =
+            serde_json::json!({ "a": 1, "b": { "x": 1, "y": 3 }, "c": 1, "e": { "y": 2 } });
+
+        merge_changes(&base, &ours, &mut theirs);
+
+        assert_eq!(
+            theirs,
+            serde_json::json!({ "a": 2, "b": { "x": 2, "y": 3 }, "e": { "x": 1, "y": 2 } })
+        );
+    }
+}
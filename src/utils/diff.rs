@@ -0,0 +1,103 @@
+use console::style;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Added(&'a str),
+    Removed(&'a str),
+}
+
+/// Line based diff of two texts using the longest common subsequence.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    // lcs[i][j] is the length of the common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = vec![];
+
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+
+    diff.extend(old[i..].iter().map(|l| DiffLine::Removed(l)));
+    diff.extend(new[j..].iter().map(|l| DiffLine::Added(l)));
+
+    diff
+}
+
+pub fn has_changes(diff: &[DiffLine]) -> bool {
+    diff.iter().any(|l| !matches!(l, DiffLine::Same(_)))
+}
+
+pub fn format_diff(diff: &[DiffLine], colors: bool) -> Vec<String> {
+    diff.iter()
+        .map(|line| {
+            let (prefix, line) = match line {
+                DiffLine::Same(l) => (" ", l),
+                DiffLine::Added(l) => ("+", l),
+                DiffLine::Removed(l) => ("-", l),
+            };
+
+            let line = format!("{prefix} {line}");
+
+            if !colors {
+                return line;
+            }
+
+            match prefix {
+                "+" => style(line).green().to_string(),
+                "-" => style(line).red().to_string(),
+                _ => style(line).dim().to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines("a\nb\nc", "a\nc\nd");
+
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Same("a"),
+                DiffLine::Removed("b"),
+                DiffLine::Same("c"),
+                DiffLine::Added("d"),
+            ]
+        );
+        assert!(has_changes(&diff));
+    }
+
+    #[test]
+    fn test_diff_lines_same() {
+        assert!(!has_changes(&diff_lines("a\nb", "a\nb")));
+    }
+}
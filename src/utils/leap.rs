@@ -0,0 +1,23 @@
+use anyhow::Result;
+use leap_client_rs::{LeapEdge, LeapOptions};
+
+use crate::state::State;
+
+/// Connects to Leap and subscribes to the channel of a project, which
+/// receives the build, rollout and container events of its deployments.
+pub async fn connect(state: &State, project_id: &str) -> Result<LeapEdge> {
+    let mut leap = LeapEdge::new(LeapOptions {
+        token: Some(&state.ctx.current.clone().unwrap().leap_token),
+        project: &state.ctx.leap_project(),
+        ws_url: &state.ctx.leap_ws_url(),
+    })
+    .await?;
+
+    if let Err(error) = leap.channel_subscribe(project_id).await {
+        leap.close().await;
+
+        return Err(error.into());
+    }
+
+    Ok(leap)
+}
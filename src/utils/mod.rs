@@ -1,18 +1,24 @@
 pub mod arisu;
 pub mod browser;
+pub mod diff;
+pub mod leap;
+pub mod output;
+pub mod prompt;
+pub mod resolve;
 pub mod size;
 pub mod sudo;
 
 use std::error::Error;
 use std::path::PathBuf;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use console::style;
 use fern::colors::{Color, ColoredLevelConfig};
 use log::{Level, LevelFilter};
-use ms::{__to_string__, ms};
+use ms::{__to_ms__, __to_string__, ms};
 use serde::Serialize;
 use serde_json::Value;
 use tokio::fs;
@@ -104,6 +110,33 @@ pub fn relative_time(date: DateTime<Utc>) -> String {
     ms!(milis.unsigned_abs(), true)
 }
 
+/// Parses a human readable duration like `30s`, `5m` or `1h`.
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    ms!(s)
+        .map(Duration::from_millis)
+        .ok_or_else(|| anyhow!("Invalid duration `{s}`, try something like `30s`, `5m` or `1h`"))
+}
+
+/// Parses an expiration date or a duration from now (60s, 1d, 30d),
+/// returning it as RFC 3339.
+pub fn parse_expiration(expires_at: &str) -> Result<String> {
+    let now = Utc::now();
+
+    let date = if expires_at.split('-').count() == 3 || expires_at.split('/').count() == 3 {
+        DateTime::<Utc>::from_str(expires_at).map_err(|_| anyhow!("Invalid date format"))?
+    } else {
+        let relative = ms!(expires_at).ok_or_else(|| anyhow!("Invalid date format"))?;
+
+        now + chrono::Duration::milliseconds(relative as i64)
+    };
+
+    if date < now {
+        return Err(anyhow!("Expiration date must be in the future"));
+    }
+
+    Ok(date.to_rfc3339())
+}
+
 pub fn ask_question_iter<T>(prompt: &str, choices: &[T], override_default: Option<T>) -> Result<T>
 where
     T: PartialEq + Clone + Serialize + Default,
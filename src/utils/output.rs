@@ -0,0 +1,63 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How command results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// human readable tables
+    #[default]
+    Text,
+    /// JSON, for scripts
+    Json,
+}
+
+pub fn print_json<T>(value: &T) -> Result<()>
+where
+    T: Serialize,
+{
+    println!("{}", serde_json::to_string_pretty(value)?);
+
+    Ok(())
+}
+
+/// How a stream of events is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum StreamOutput {
+    /// one human readable line per event
+    #[default]
+    Text,
+    /// one JSON object per line, for `jq` or log shippers
+    Ndjson,
+}
+
+/// How a newly created token is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum TokenOutput {
+    /// the token with a short explanation
+    #[default]
+    Text,
+    /// `TOKEN=<token>`, to pipe into a secret store
+    Env,
+    /// the created token as JSON
+    Json,
+}
+
+pub fn print_token<T>(output: TokenOutput, token: &str, created: &T) -> Result<()>
+where
+    T: Serialize,
+{
+    match output {
+        TokenOutput::Text => {
+            log::warn!("Make sure to copy the token now, it will not be shown again");
+
+            println!("{token}");
+        }
+
+        TokenOutput::Env => println!("TOKEN={token}"),
+
+        TokenOutput::Json => print_json(created)?,
+    }
+
+    Ok(())
+}
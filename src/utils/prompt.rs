@@ -0,0 +1,223 @@
+//! Prompts that fail with a clear error instead of waiting for input
+//! when prompts are disabled, see `--no-input`.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::{anyhow, Result};
+use dialoguer::{Confirm, Editor, Input, MultiSelect, Password, Select};
+
+use crate::error::HopError;
+
+static NO_INPUT: AtomicBool = AtomicBool::new(false);
+
+/// Lists longer than this are searched before picking
+const FUZZY_THRESHOLD: usize = 10;
+
+/// The argument that answers a prompt, used in the error when prompts are disabled.
+#[derive(Debug, Clone, Copy)]
+pub struct Arg<'a> {
+    pub name: &'a str,
+    /// how to pass the argument, e.g. `<DEPLOYMENT>` or `--image`
+    pub pass: &'a str,
+}
+
+impl<'a> Arg<'a> {
+    pub const fn new(name: &'a str, pass: &'a str) -> Self {
+        Self { name, pass }
+    }
+
+    fn missing(&self) -> anyhow::Error {
+        HopError::Usage(format!(
+            "Missing argument `{}`, pass {}",
+            self.name, self.pass
+        ))
+        .into()
+    }
+}
+
+pub fn set_no_input(no_input: bool) {
+    NO_INPUT.store(no_input, Ordering::Relaxed);
+}
+
+pub fn is_interactive() -> bool {
+    !NO_INPUT.load(Ordering::Relaxed)
+}
+
+/// For flows that ask many questions, fails before the first one.
+pub fn ensure_interactive(arg: Arg) -> Result<()> {
+    if is_interactive() {
+        Ok(())
+    } else {
+        Err(arg.missing())
+    }
+}
+
+/// Asks to pick one of `items`, cancelling is an error.
+pub fn select<T>(prompt: impl Into<String>, items: &[T], default: usize, arg: Arg) -> Result<usize>
+where
+    T: ToString,
+{
+    ensure_interactive(arg)?;
+
+    Select::new()
+        .with_prompt(prompt)
+        .items(items)
+        .default(default)
+        .interact_opt()?
+        .ok_or_else(|| anyhow!("No {} selected", arg.name))
+}
+
+/// Like `select`, but long lists are narrowed down with a search first.
+pub fn fuzzy_select<T>(prompt: impl Into<String>, items: &[T], arg: Arg) -> Result<usize>
+where
+    T: ToString,
+{
+    ensure_interactive(arg)?;
+
+    let prompt = prompt.into();
+
+    if items.len() <= FUZZY_THRESHOLD {
+        return select(prompt, items, 0, arg);
+    }
+
+    let items = items.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    loop {
+        let query = Input::<String>::new()
+            .with_prompt(format!("Search for a {}", arg.name))
+            .allow_empty(true)
+            .interact_text()?;
+
+        let matches = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| fuzzy_match(&query, item))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        if matches.is_empty() {
+            log::warn!("Nothing matches `{query}`");
+
+            continue;
+        }
+
+        let labels = matches.iter().map(|idx| &items[*idx]).collect::<Vec<_>>();
+
+        return Ok(matches[select(prompt, &labels, 0, arg)?]);
+    }
+}
+
+/// Whether the characters of `query` appear in `text` in order, ignoring case.
+pub fn fuzzy_match(query: &str, text: &str) -> bool {
+    let text = text.to_lowercase();
+    let mut chars = text.chars();
+
+    query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|q| chars.any(|c| c == q))
+}
+
+/// Asks to pick any number of `items`, cancelling is an error.
+pub fn multi_select<T>(prompt: impl Into<String>, items: &[T], arg: Arg) -> Result<Vec<usize>>
+where
+    T: ToString,
+{
+    ensure_interactive(arg)?;
+
+    MultiSelect::new()
+        .with_prompt(prompt)
+        .items(items)
+        .interact_opt()?
+        .ok_or_else(|| anyhow!("No {} selected", arg.name))
+}
+
+/// Asks a yes or no question, cancelling answers no. When prompts are
+/// disabled it fails and names the flag that skips the question.
+pub fn confirm(prompt: impl Into<String>, default: bool, skip_flag: &str) -> Result<bool> {
+    if !is_interactive() {
+        return Err(
+            HopError::Usage(format!("Confirmation needed, pass {skip_flag} to skip it")).into(),
+        );
+    }
+
+    Ok(Confirm::new()
+        .with_prompt(prompt)
+        .default(default)
+        .interact_opt()?
+        .unwrap_or(false))
+}
+
+/// Text input, configure it further before calling `interact_text`.
+pub fn input<T>(prompt: impl Into<String>, arg: Arg) -> Result<Input<'static, T>>
+where
+    T: Clone + ToString + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    ensure_interactive(arg)?;
+
+    let mut input = Input::new();
+    input.with_prompt(prompt);
+
+    Ok(input)
+}
+
+/// Hidden input, configure it further before calling `interact`.
+pub fn password(prompt: impl Into<String>, arg: Arg) -> Result<Password<'static>> {
+    ensure_interactive(arg)?;
+
+    let mut password = Password::new();
+    password.with_prompt(prompt);
+
+    Ok(password)
+}
+
+/// Opens an editor, `alternative` tells how to do without one.
+pub fn editor(alternative: &str) -> Result<Editor> {
+    if !is_interactive() {
+        return Err(HopError::Usage(format!(
+            "Can not open an editor when prompts are disabled, {alternative}"
+        ))
+        .into());
+    }
+
+    Ok(Editor::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_input() {
+        set_no_input(true);
+
+        let error = select(
+            "Select a deployment",
+            &["a"],
+            0,
+            Arg::new("deployment", "<DEPLOYMENT>"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Missing argument `deployment`, pass <DEPLOYMENT>"
+        );
+        assert_eq!(HopError::exit_code_of(&error), 2);
+        assert!(confirm("Delete?", false, "--force").is_err());
+
+        set_no_input(false);
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("api", "my-API-server"));
+        assert!(fuzzy_match("mas", "my-api-server"));
+        assert!(fuzzy_match("", "anything"));
+        assert!(!fuzzy_match("sam", "my-api-server"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Finds the resource the user meant, by ID or by name.
+
+use anyhow::Result;
+
+use crate::error::HopError;
+
+pub trait Resource {
+    /// Singular name of the resource, also the prefix of its IDs
+    const KIND: &'static str;
+
+    fn id(&self) -> &str;
+    fn name(&self) -> Option<&str>;
+}
+
+/// Whether `value` looks like the ID of a `T`, e.g. `deployment_...`.
+pub fn is_id<T: Resource>(value: &str) -> bool {
+    value
+        .strip_prefix(T::KIND)
+        .is_some_and(|rest| rest.starts_with('_'))
+}
+
+/// Finds a resource by its ID or its name, names are case insensitive.
+pub fn find<'a, T: Resource>(items: &'a [T], query: &str) -> Result<&'a T> {
+    if let Some(item) = items.iter().find(|item| item.id() == query) {
+        return Ok(item);
+    }
+
+    let query_lower = query.to_lowercase();
+
+    let matches = items
+        .iter()
+        .filter(|item| item.name().map(str::to_lowercase).as_deref() == Some(&query_lower))
+        .collect::<Vec<_>>();
+
+    match matches.as_slice() {
+        [] => {
+            Err(HopError::NotFound(format!("No {} with the ID or name `{query}`", T::KIND)).into())
+        }
+
+        [item] => Ok(item),
+
+        _ => Err(HopError::Usage(format!(
+            "More than one {} is named `{query}`, pass the ID instead: {}",
+            T::KIND,
+            matches
+                .iter()
+                .map(|item| item.id())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Thing(&'static str, &'static str);
+
+    impl Resource for Thing {
+        const KIND: &'static str = "thing";
+
+        fn id(&self) -> &str {
+            self.0
+        }
+
+        fn name(&self) -> Option<&str> {
+            Some(self.1)
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let things = [
+            Thing("thing_1", "api"),
+            Thing("thing_2", "Web"),
+            Thing("thing_3", "web"),
+        ];
+
+        assert!(is_id::<Thing>("thing_1"));
+        assert!(!is_id::<Thing>("things"));
+
+        assert_eq!(find(&things, "thing_2").unwrap().0, "thing_2");
+        assert_eq!(find(&things, "API").unwrap().0, "thing_1");
+
+        let error = find(&things, "web").unwrap_err();
+        assert_eq!(HopError::exit_code_of(&error), 2);
+        assert!(error.to_string().ends_with("thing_2, thing_3"));
+
+        let error = find(&things, "db").unwrap_err();
+        assert_eq!(HopError::exit_code_of(&error), 6);
+    }
+}